@@ -0,0 +1,121 @@
+use crate::math::powf;
+use crate::HexColor;
+
+// The constants of APCA-W3 0.0.98G-4g, named like in the reference
+// implementation.
+
+const MAIN_TRC: f32 = 2.4;
+const S_R_CO: f32 = 0.212_672_9;
+const S_G_CO: f32 = 0.715_152_2;
+const S_B_CO: f32 = 0.072_175;
+
+const NORM_BG: f32 = 0.56;
+const NORM_TXT: f32 = 0.57;
+const REV_TXT: f32 = 0.62;
+const REV_BG: f32 = 0.65;
+
+const BLK_THRS: f32 = 0.022;
+const BLK_CLMP: f32 = 1.414;
+const SCALE_BOW: f32 = 1.14;
+const SCALE_WOB: f32 = 1.14;
+const LO_BOW_OFFSET: f32 = 0.027;
+const LO_WOB_OFFSET: f32 = 0.027;
+const DELTA_Y_MIN: f32 = 0.000_5;
+const LO_CLIP: f32 = 0.1;
+
+/// Returns the screen luminance of a color as estimated by APCA, with the
+/// soft clamp for near-black colors already applied.
+fn luminance(color: HexColor) -> f32 {
+    let [r, g, b] = [color.r, color.g, color.b].map(|c| powf(f32::from(c) / 255.0, MAIN_TRC));
+    let y = S_R_CO * r + S_G_CO * g + S_B_CO * b;
+    if y > BLK_THRS {
+        y
+    } else {
+        y + powf(BLK_THRS - y, BLK_CLMP)
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Returns the lightness contrast (Lc) of `text` on `background` as
+    /// computed by the [APCA-W3] algorithm (version 0.0.98G-4g), which is
+    /// being developed for WCAG 3.
+    ///
+    /// Unlike [`HexColor::contrast_ratio`], APCA isn't symmetric: the result
+    /// is positive for dark text on a light background, and negative for
+    /// light text on a dark background, which needs a little more contrast
+    /// to be as readable. Its magnitude goes up to about `106.0` and `108.0`,
+    /// respectively. Like in the reference implementation, a magnitude below
+    /// `10.0` before the low-contrast offset of `2.7` is subtracted is
+    /// reported as `0.0`, i.e., as no contrast at all, so the smallest
+    /// magnitude that isn't is `7.3`.
+    ///
+    /// As a guideline, a magnitude below `15.0` isn't readable at all, and
+    /// for body text, `75.0` is the rough equivalent of the 4.5:1 ratio of
+    /// WCAG 2.
+    ///
+    /// A translucent `text` is composited over the `background` first, which
+    /// is treated as opaque.
+    ///
+    /// [APCA-W3]: https://github.com/Myndex/apca-w3
+    ///
+    /// # Examples
+    ///
+    /// The test vectors of the reference implementation:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let lc = |text: u32, background: u32| {
+    ///     HexColor::apca_contrast(HexColor::from_u24(text), HexColor::from_u24(background))
+    /// };
+    ///
+    /// assert!((lc(0x888888, 0xFFFFFF) - 63.056_47).abs() < 1e-3);
+    /// assert!((lc(0xFFFFFF, 0x888888) + 68.541_46).abs() < 1e-3);
+    /// assert!((lc(0x000000, 0xAAAAAA) - 58.146_263).abs() < 1e-3);
+    /// assert!((lc(0xAAAAAA, 0x000000) + 56.241_133).abs() < 1e-3);
+    /// assert!((lc(0x112233, 0xDDEEFF) - 91.668_31).abs() < 1e-3);
+    /// assert!((lc(0xDDEEFF, 0x112233) + 93.067_7).abs() < 1e-3);
+    /// assert!((lc(0x112233, 0x444444) - 8.323_261).abs() < 1e-3);
+    /// assert!((lc(0x444444, 0x112233) + 7.526_878).abs() < 1e-3);
+    /// ```
+    ///
+    /// Colors that are too similar have no contrast at all:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// assert_eq!(HexColor::apca_contrast(HexColor::GRAY, HexColor::GRAY), 0.0);
+    /// assert_eq!(
+    ///     HexColor::apca_contrast(HexColor::achromatic(200), HexColor::achromatic(210)),
+    ///     0.0,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn apca_contrast(text: HexColor, background: HexColor) -> f32 {
+        let y_txt = luminance(text.over_opaque(background));
+        let y_bg = luminance(background);
+        if (y_bg - y_txt).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let output = if y_bg > y_txt {
+            // Dark text on a light background.
+            let sapc = (powf(y_bg, NORM_BG) - powf(y_txt, NORM_TXT)) * SCALE_BOW;
+            if sapc < LO_CLIP {
+                0.0
+            } else {
+                sapc - LO_BOW_OFFSET
+            }
+        } else {
+            // Light text on a dark background.
+            let sapc = (powf(y_bg, REV_BG) - powf(y_txt, REV_TXT)) * SCALE_WOB;
+            if sapc > -LO_CLIP {
+                0.0
+            } else {
+                sapc + LO_WOB_OFFSET
+            }
+        };
+        output * 100.0
+    }
+}
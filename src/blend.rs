@@ -0,0 +1,259 @@
+use crate::math::sqrt;
+use crate::{round_to_u8, CompositeOp, HexColor};
+
+/// A blend mode of [Compositing and Blending Level 1], which determines the
+/// color that's drawn where a source overlaps a backdrop, before the source
+/// is composited over it.
+///
+/// In the descriptions below, the source is the color being drawn, and the
+/// backdrop is the color being drawn onto. The names match the values of the
+/// CSS `mix-blend-mode` property.
+///
+/// [Compositing and Blending Level 1]: https://www.w3.org/TR/compositing-1/#blending
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub enum BlendMode {
+    /// `normal`: the source replaces the backdrop.
+    #[default]
+    Normal,
+    /// `multiply`: the source and the backdrop are multiplied, which always
+    /// makes the backdrop darker, like overlapping slides on a projector.
+    Multiply,
+    /// `screen`: the complements of the source and the backdrop are
+    /// multiplied, which always makes the backdrop lighter.
+    Screen,
+    /// `overlay`: the backdrop is multiplied where it's dark and screened
+    /// where it's light, i.e., [`BlendMode::HardLight`] with the source and
+    /// the backdrop swapped.
+    Overlay,
+    /// `darken`: the darker of the source and the backdrop is used, per
+    /// component.
+    Darken,
+    /// `lighten`: the lighter of the source and the backdrop is used, per
+    /// component.
+    Lighten,
+    /// `color-dodge`: the backdrop is brightened to reflect the source.
+    ColorDodge,
+    /// `color-burn`: the backdrop is darkened to reflect the source.
+    ColorBurn,
+    /// `hard-light`: the backdrop is multiplied where the source is dark and
+    /// screened where the source is light, like shining a harsh spotlight on
+    /// it.
+    HardLight,
+    /// `soft-light`: the backdrop is darkened where the source is dark and
+    /// lightened where the source is light, like shining a diffused spotlight
+    /// on it.
+    SoftLight,
+    /// `difference`: the darker of the source and the backdrop is subtracted
+    /// from the lighter one, per component.
+    Difference,
+    /// `exclusion`: like [`BlendMode::Difference`], but with less contrast.
+    Exclusion,
+    /// `hue`: the hue of the source with the saturation and luminosity of the
+    /// backdrop.
+    Hue,
+    /// `saturation`: the saturation of the source with the hue and
+    /// luminosity of the backdrop.
+    Saturation,
+    /// `color`: the hue and saturation of the source with the luminosity of
+    /// the backdrop, which is handy for tinting.
+    Color,
+    /// `luminosity`: the luminosity of the source with the hue and saturation
+    /// of the backdrop.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Blends the components of `source` and `backdrop`, which are in the
+    /// range `0.0..=1.0`.
+    fn apply(self, backdrop: [f32; 3], source: [f32; 3]) -> [f32; 3] {
+        let [br, bg, bb] = backdrop;
+        let [sr, sg, sb] = source;
+        let separable = |f: fn(f32, f32) -> f32| [f(br, sr), f(bg, sg), f(bb, sb)];
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => separable(multiply),
+            BlendMode::Screen => separable(screen),
+            BlendMode::Overlay => separable(|cb, cs| hard_light(cs, cb)),
+            BlendMode::Darken => separable(f32::min),
+            BlendMode::Lighten => separable(f32::max),
+            BlendMode::ColorDodge => separable(color_dodge),
+            BlendMode::ColorBurn => separable(color_burn),
+            BlendMode::HardLight => separable(hard_light),
+            BlendMode::SoftLight => separable(soft_light),
+            BlendMode::Difference => separable(|cb, cs| (cb - cs).abs()),
+            BlendMode::Exclusion => separable(|cb, cs| cb + cs - 2.0 * cb * cs),
+            BlendMode::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            BlendMode::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            BlendMode::Color => set_lum(source, lum(backdrop)),
+            BlendMode::Luminosity => set_lum(backdrop, lum(source)),
+        }
+    }
+}
+
+// The functions below are named and defined like in the specification, with
+// `cb` as the backdrop and `cs` as the source.
+
+fn multiply(cb: f32, cs: f32) -> f32 {
+    cb * cs
+}
+
+fn screen(cb: f32, cs: f32) -> f32 {
+    cb + cs - cb * cs
+}
+
+fn color_dodge(cb: f32, cs: f32) -> f32 {
+    if cb <= 0.0 {
+        0.0
+    } else if cs >= 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+}
+
+fn color_burn(cb: f32, cs: f32) -> f32 {
+    if cb >= 1.0 {
+        1.0
+    } else if cs <= 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+}
+
+fn hard_light(cb: f32, cs: f32) -> f32 {
+    if cs <= 0.5 {
+        multiply(cb, 2.0 * cs)
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+fn soft_light(cb: f32, cs: f32) -> f32 {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        let d = if cb <= 0.25 {
+            ((16.0 * cb - 12.0) * cb + 4.0) * cb
+        } else {
+            sqrt(cb)
+        };
+        cb + (2.0 * cs - 1.0) * (d - cb)
+    }
+}
+
+fn lum([r, g, b]: [f32; 3]) -> f32 {
+    0.3 * r + 0.59 * g + 0.11 * b
+}
+
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut c = c;
+    if n < 0.0 {
+        c = c.map(|c| l + (c - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        c = c.map(|c| l + (c - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|c| c + d))
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let min = c[0].min(c[1]).min(c[2]);
+    let max = c[0].max(c[1]).max(c[2]);
+    if max > min {
+        c.map(|c| (c - min) * s / (max - min))
+    } else {
+        [0.0; 3]
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Draws the color, as the source, onto `backdrop` with the blend mode
+    /// `mode`, and returns the result.
+    ///
+    /// Following [Compositing and Blending Level 1], the source is first
+    /// blended with the backdrop, as far as the backdrop is opaque, and the
+    /// result is then composited over the backdrop like
+    /// [`CompositeOp::SrcOver`] does. Like in browsers, the
+    /// gamma-encoded components are blended.
+    ///
+    /// [Compositing and Blending Level 1]: https://www.w3.org/TR/compositing-1/#blending
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{BlendMode, HexColor};
+    ///
+    /// let orange = HexColor::rgb(255, 128, 0);
+    /// let sky = HexColor::rgb(135, 206, 235);
+    ///
+    /// assert_eq!(orange.blend(sky, BlendMode::Normal), orange);
+    /// assert_eq!(orange.blend(sky, BlendMode::Multiply), HexColor::rgb(135, 103, 0));
+    /// assert_eq!(orange.blend(sky, BlendMode::Screen), HexColor::rgb(255, 231, 235));
+    /// assert_eq!(orange.blend(sky, BlendMode::Difference), HexColor::rgb(120, 78, 235));
+    ///
+    /// // Tinting a gray with the hue and saturation of orange:
+    /// let tinted = orange.blend(HexColor::GRAY, BlendMode::Color);
+    /// assert_eq!(tinted, HexColor::rgb(215, 108, 0));
+    ///
+    /// // A translucent source only partially blends:
+    /// let translucent = HexColor::rgba(0, 0, 0, 128);
+    /// assert_eq!(translucent.blend(sky, BlendMode::Multiply), HexColor::rgb(67, 103, 117));
+    /// ```
+    ///
+    /// Blending with [`BlendMode::Normal`] is the same as compositing with
+    /// [`CompositeOp::SrcOver`]:
+    ///
+    /// ```
+    /// use hex_color::{BlendMode, CompositeOp, HexColor};
+    ///
+    /// for n in (0..=0xFFFF_FFFF_u32).step_by(999_983) {
+    ///     let source = HexColor::from_u32(n);
+    ///     let backdrop = HexColor::from_u32(n.rotate_left(13));
+    ///     assert_eq!(
+    ///         source.blend(backdrop, BlendMode::Normal),
+    ///         source.composite(backdrop, CompositeOp::SrcOver),
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn blend(self, backdrop: HexColor, mode: BlendMode) -> HexColor {
+        // Compositing on its own can be done exactly.
+        if mode == BlendMode::Normal {
+            return self.composite(backdrop, CompositeOp::SrcOver);
+        }
+
+        let unit = |color: HexColor| [color.r, color.g, color.b].map(|c| f32::from(c) / 255.0);
+        let (cs, cb) = (unit(self), unit(backdrop));
+        let (alpha_s, alpha_b) = (f32::from(self.a) / 255.0, f32::from(backdrop.a) / 255.0);
+
+        let blended = mode.apply(cb, cs);
+        let alpha = alpha_s + alpha_b * (1.0 - alpha_s);
+        if alpha <= 0.0 {
+            return HexColor::CLEAR;
+        }
+        let mut components = [0.0; 3];
+        for (i, component) in components.iter_mut().enumerate() {
+            let source = (1.0 - alpha_b) * cs[i] + alpha_b * blended[i];
+            let premultiplied = alpha_s * source + alpha_b * (1.0 - alpha_s) * cb[i];
+            *component = premultiplied / alpha;
+        }
+
+        let [r, g, b] = components.map(|c| round_to_u8(c.clamp(0.0, 1.0) * 255.0));
+        HexColor::rgba(r, g, b, round_to_u8(alpha * 255.0))
+    }
+}
@@ -0,0 +1,225 @@
+use core::fmt;
+
+use crate::{round_to_u8, HexColor};
+
+/// A color in the naive CMYK (cyan, magenta, yellow, key) model, with each
+/// component in the range `0.0..=1.0`.
+///
+/// The conversions don't know anything about inks, paper, or ICC profiles;
+/// they treat cyan, magenta, and yellow as exact complements of red, green,
+/// and blue. That's good enough to hand percentages to a print shop, but not
+/// for color-accurate proofing.
+///
+/// CMYK has no notion of transparency, so there's no alpha component. The
+/// conversions come in pairs, like [`HexColor::rgb`] and
+/// [`HexColor::rgba`], to either drop the alpha component or carry it
+/// alongside.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{BlackGeneration, HexColor};
+///
+/// let orange = HexColor::rgb(255, 143, 0);
+/// let cmyk = orange.to_cmyk(BlackGeneration::default());
+///
+/// assert_eq!(cmyk.to_string(), "cmyk(0%, 44%, 100%, 0%)");
+/// assert_eq!(format!("{cmyk:.1}"), "cmyk(0.0%, 43.9%, 100.0%, 0.0%)");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Cmyk {
+    /// The cyan component of the color.
+    pub c: f32,
+    /// The magenta component of the color.
+    pub m: f32,
+    /// The yellow component of the color.
+    pub y: f32,
+    /// The key, i.e., black, component of the color.
+    pub k: f32,
+}
+
+impl Cmyk {
+    /// Constructs a new `Cmyk` color from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(c: f32, m: f32, y: f32, k: f32) -> Cmyk {
+        Cmyk { c, m, y, k }
+    }
+}
+
+/// How much of a color's gray component, i.e., the part that equal amounts of
+/// cyan, magenta, and yellow make up, is printed with black ink instead.
+///
+/// The default is full gray component replacement, which is what the naive
+/// formula found everywhere does.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlackGeneration {
+    /// Gray component replacement (GCR): the fraction `amount`, in the range
+    /// `0.0..=1.0`, of the gray component is replaced by black everywhere.
+    ///
+    /// An `amount` of `0.0` uses no black at all, and `1.0` replaces the
+    /// whole gray component, so every color uses at most two of cyan,
+    /// magenta, and yellow.
+    Gcr {
+        /// The fraction of the gray component to replace.
+        amount: f32,
+    },
+    /// Under color removal (UCR): the gray component is replaced by black
+    /// only in the shadows, starting from none where it's `start`, in the
+    /// range `0.0..=1.0`, and ramping up to all of it where it's `1.0`.
+    Ucr {
+        /// How dark the gray component must be before black is used.
+        start: f32,
+    },
+}
+
+impl BlackGeneration {
+    /// Returns the amount of black to use for the gray component `gray`.
+    fn black(self, gray: f32) -> f32 {
+        match self {
+            BlackGeneration::Gcr { amount } => amount.clamp(0.0, 1.0) * gray,
+            BlackGeneration::Ucr { start } => {
+                let start = start.clamp(0.0, 1.0);
+                if gray <= start {
+                    0.0
+                } else {
+                    (gray - start) / (1.0 - start) * gray
+                }
+            }
+        }
+    }
+}
+
+impl Default for BlackGeneration {
+    /// Returns full gray component replacement.
+    fn default() -> Self {
+        BlackGeneration::Gcr { amount: 1.0 }
+    }
+}
+
+impl HexColor {
+    /// Converts the color to [`Cmyk`], dropping the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{BlackGeneration, Cmyk, HexColor};
+    ///
+    /// let navy = HexColor::rgb(0, 0, 128);
+    ///
+    /// let full = navy.to_cmyk(BlackGeneration::Gcr { amount: 1.0 });
+    /// assert_eq!(full.to_string(), "cmyk(100%, 100%, 0%, 50%)");
+    ///
+    /// let none = navy.to_cmyk(BlackGeneration::Gcr { amount: 0.0 });
+    /// assert_eq!(none.to_string(), "cmyk(100%, 100%, 50%, 0%)");
+    ///
+    /// let ucr = navy.to_cmyk(BlackGeneration::Ucr { start: 0.25 });
+    /// assert_eq!(ucr.to_string(), "cmyk(100%, 100%, 40%, 16%)");
+    ///
+    /// // Only the shadows get any black:
+    /// let gray = HexColor::achromatic(192).to_cmyk(BlackGeneration::Ucr { start: 0.25 });
+    /// assert_eq!(gray.k, 0.0);
+    /// ```
+    #[must_use]
+    pub fn to_cmyk(self, black: BlackGeneration) -> Cmyk {
+        let [c, m, y] = [self.r, self.g, self.b].map(|v| 1.0 - f32::from(v) / 255.0);
+        let k = black.black(c.min(m).min(y));
+        if k >= 1.0 {
+            return Cmyk::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let remove = |v: f32| (v - k) / (1.0 - k);
+        Cmyk::new(remove(c), remove(m), remove(y), k)
+    }
+
+    /// Converts the color to [`Cmyk`], returning the alpha component
+    /// alongside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{BlackGeneration, Cmyk, HexColor};
+    ///
+    /// let translucent_red = HexColor::rgba(255, 0, 0, 128);
+    /// let (cmyk, a) = translucent_red.to_cmyka(BlackGeneration::default());
+    ///
+    /// assert_eq!(cmyk, Cmyk::new(0.0, 1.0, 1.0, 0.0));
+    /// assert_eq!(a, 128);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_cmyka(self, black: BlackGeneration) -> (Cmyk, u8) {
+        (self.to_cmyk(black), self.a)
+    }
+
+    /// Converts a [`Cmyk`] color to the nearest opaque `HexColor`.
+    ///
+    /// Every component is clamped to the range `0.0..=1.0` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Cmyk, HexColor};
+    ///
+    /// assert_eq!(HexColor::from_cmyk(Cmyk::new(0.0, 0.0, 0.0, 1.0)), HexColor::BLACK);
+    /// assert_eq!(HexColor::from_cmyk(Cmyk::new(1.0, 0.0, 0.0, 0.0)), HexColor::CYAN);
+    /// assert_eq!(HexColor::from_cmyk(Cmyk::new(0.0, 0.0, 0.0, 0.5)), HexColor::GRAY);
+    /// ```
+    ///
+    /// Converting to [`Cmyk`] and back is lossless for every opaque color, no
+    /// matter how much black is used:
+    ///
+    /// ```
+    /// use hex_color::{BlackGeneration, HexColor};
+    ///
+    /// for black in [
+    ///     BlackGeneration::Gcr { amount: 0.0 },
+    ///     BlackGeneration::Gcr { amount: 0.5 },
+    ///     BlackGeneration::Gcr { amount: 1.0 },
+    ///     BlackGeneration::Ucr { start: 0.5 },
+    /// ] {
+    ///     for n in (0..=0xFF_FFFF).step_by(101) {
+    ///         let color = HexColor::from_u24(n);
+    ///         assert_eq!(HexColor::from_cmyk(color.to_cmyk(black)), color);
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_cmyk(cmyk: Cmyk) -> HexColor {
+        HexColor::from_cmyka(cmyk, u8::MAX)
+    }
+
+    /// Converts a [`Cmyk`] color to the nearest `HexColor` with the given
+    /// alpha component.
+    ///
+    /// Every component is clamped to the range `0.0..=1.0` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Cmyk, HexColor};
+    ///
+    /// let cmyk = Cmyk::new(0.0, 1.0, 1.0, 0.0);
+    /// assert_eq!(HexColor::from_cmyka(cmyk, 128), HexColor::rgba(255, 0, 0, 128));
+    /// ```
+    #[must_use]
+    pub fn from_cmyka(cmyk: Cmyk, a: u8) -> HexColor {
+        let white = 1.0 - cmyk.k.clamp(0.0, 1.0);
+        let [r, g, b] = [cmyk.c, cmyk.m, cmyk.y]
+            .map(|v| round_to_u8((1.0 - v.clamp(0.0, 1.0)) * white * 255.0));
+        HexColor::rgba(r, g, b, a)
+    }
+}
+
+impl fmt::Display for Cmyk {
+    /// Formats the color like `cmyk(0%, 44%, 100%, 0%)`. The precision of
+    /// the percentages is `0` unless specified otherwise, e.g., with `{:.1}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let [c, m, y, k] = [self.c, self.m, self.y, self.k].map(|v| v * 100.0);
+        write!(
+            f,
+            "cmyk({c:.precision$}%, {m:.precision$}%, {y:.precision$}%, {k:.precision$}%)"
+        )
+    }
+}
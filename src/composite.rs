@@ -0,0 +1,149 @@
+use crate::HexColor;
+
+/// A [Porter-Duff] compositing operator, which determines how much of the
+/// source and of the destination show through where they overlap.
+///
+/// The descriptions below are in terms of the source, i.e., the color being
+/// drawn, and the destination, i.e., the color being drawn onto.
+///
+/// [Porter-Duff]: https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum CompositeOp {
+    /// Neither the source nor the destination is shown.
+    Clear,
+    /// Only the source is shown.
+    Src,
+    /// Only the destination is shown.
+    Dst,
+    /// The source is placed over the destination. This is what "drawing" a
+    /// color usually means.
+    #[default]
+    SrcOver,
+    /// The destination is placed over the source.
+    DstOver,
+    /// The source is shown only where the destination is.
+    SrcIn,
+    /// The destination is shown only where the source is.
+    DstIn,
+    /// The source is shown only where the destination isn't.
+    SrcOut,
+    /// The destination is shown only where the source isn't.
+    DstOut,
+    /// The source is placed over the destination, but only where the
+    /// destination is.
+    SrcAtop,
+    /// The destination is placed over the source, but only where the source
+    /// is.
+    DstAtop,
+    /// The source and the destination are each shown only where the other
+    /// isn't.
+    Xor,
+}
+
+impl CompositeOp {
+    /// Returns the fractions, scaled by `255`, of the source and of the
+    /// destination that are shown, given the alpha components of the source
+    /// and of the destination.
+    const fn factors(self, src_a: u8, dst_a: u8) -> (u32, u32) {
+        let (src_a, dst_a) = (src_a as u32, dst_a as u32);
+        match self {
+            CompositeOp::Clear => (0, 0),
+            CompositeOp::Src => (255, 0),
+            CompositeOp::Dst => (0, 255),
+            CompositeOp::SrcOver => (255, 255 - src_a),
+            CompositeOp::DstOver => (255 - dst_a, 255),
+            CompositeOp::SrcIn => (dst_a, 0),
+            CompositeOp::DstIn => (0, src_a),
+            CompositeOp::SrcOut => (255 - dst_a, 0),
+            CompositeOp::DstOut => (0, 255 - src_a),
+            CompositeOp::SrcAtop => (dst_a, 255 - src_a),
+            CompositeOp::DstAtop => (255 - dst_a, src_a),
+            CompositeOp::Xor => (255 - dst_a, 255 - src_a),
+        }
+    }
+}
+
+impl HexColor {
+    /// Composites the color, as the source, with `dst`, as the destination,
+    /// with the Porter-Duff operator `op`.
+    ///
+    /// Both colors have straight, i.e., not premultiplied, alpha, and so
+    /// does the result. It's computed with exact integer arithmetic, so every
+    /// component, including alpha, is correctly rounded. Where the result is
+    /// completely transparent, [`HexColor::CLEAR`] is returned.
+    ///
+    /// Unlike [`HexColor::add`] and its siblings, which are component-wise
+    /// arithmetic, compositing works on the gamma-encoded components like
+    /// browsers do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{CompositeOp, HexColor};
+    ///
+    /// let red = HexColor::rgba(255, 0, 0, 128);
+    /// let blue = HexColor::BLUE;
+    ///
+    /// assert_eq!(red.composite(blue, CompositeOp::SrcOver), HexColor::rgb(128, 0, 127));
+    /// assert_eq!(red.composite(blue, CompositeOp::DstOver), HexColor::BLUE);
+    /// assert_eq!(red.composite(blue, CompositeOp::SrcIn), red);
+    /// assert_eq!(red.composite(blue, CompositeOp::SrcOut), HexColor::CLEAR);
+    /// assert_eq!(red.composite(blue, CompositeOp::DstOut), HexColor::rgba(0, 0, 255, 127));
+    ///
+    /// // Both translucent:
+    /// let blue = HexColor::rgba(0, 0, 255, 128);
+    /// assert_eq!(red.composite(blue, CompositeOp::SrcOver), HexColor::rgba(170, 0, 85, 192));
+    /// assert_eq!(red.composite(blue, CompositeOp::Xor), HexColor::rgba(128, 0, 128, 127));
+    ///
+    /// // Alpha rounds to zero, so nothing is left to see:
+    /// let red = HexColor::rgba(255, 0, 0, 1);
+    /// let blue = HexColor::rgba(0, 0, 255, 1);
+    /// assert_eq!(red.composite(blue, CompositeOp::SrcIn), HexColor::CLEAR);
+    /// ```
+    ///
+    /// Drawing anything over an opaque color is the usual weighted average:
+    ///
+    /// ```
+    /// use hex_color::{CompositeOp, HexColor};
+    ///
+    /// for a in 0..=255 {
+    ///     let src = HexColor::WHITE.with_a(a);
+    ///     assert_eq!(
+    ///         src.composite(HexColor::BLACK, CompositeOp::SrcOver),
+    ///         HexColor::achromatic(a),
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn composite(self, dst: HexColor, op: CompositeOp) -> HexColor {
+        // The result is a weighted average of `src` and `dst`, so it fits.
+        #[allow(clippy::cast_possible_truncation)]
+        const fn blend(src: u8, src_weight: u32, dst: u8, dst_weight: u32, total: u32) -> u8 {
+            let sum = src as u32 * src_weight + dst as u32 * dst_weight;
+            ((2 * sum + total) / (2 * total)) as u8
+        }
+
+        let (src_factor, dst_factor) = op.factors(self.a, dst.a);
+        // The weights of the source and the destination, and the alpha of the
+        // result, all scaled by `255 * 255`.
+        let src_weight = src_factor * self.a as u32;
+        let dst_weight = dst_factor * dst.a as u32;
+        let total = src_weight + dst_weight;
+
+        // The alpha of the result is at most `1`, i.e., `total` is at most
+        // `255 * 255`.
+        #[allow(clippy::cast_possible_truncation)]
+        let a = ((total + 127) / 255) as u8;
+        // This also covers `total` being `0`, which the components can't be
+        // divided by.
+        if a == 0 {
+            return HexColor::CLEAR;
+        }
+        HexColor::rgba(
+            blend(self.r, src_weight, dst.r, dst_weight, total),
+            blend(self.g, src_weight, dst.g, dst_weight, total),
+            blend(self.b, src_weight, dst.b, dst_weight, total),
+            a,
+        )
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::Oklch;
+use crate::{CompositeOp, HexColor, LinearRgba};
+
+/// The size of text, as far as the [WCAG 2.1] contrast requirements are
+/// concerned.
+///
+/// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum TextSize {
+    /// Text smaller than [`TextSize::Large`].
+    #[default]
+    Normal,
+    /// Text that's at least 18 points, or at least 14 points and bold. For
+    /// most fonts, that's 24 and 18.66 CSS pixels, respectively.
+    Large,
+}
+
+/// A conformance level of the [WCAG 2.1] contrast requirements, ordered from
+/// least to most strict.
+///
+/// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Level AA, i.e., success criterion 1.4.3 (Contrast (Minimum)): a
+    /// contrast ratio of at least 4.5:1, or 3:1 for large text.
+    AA,
+    /// Level AAA, i.e., success criterion 1.4.6 (Contrast (Enhanced)): a
+    /// contrast ratio of at least 7:1, or 4.5:1 for large text.
+    AAA,
+}
+
+impl HexColor {
+    /// Returns the relative luminance of the color as defined by [WCAG 2.1],
+    /// from `0.0` for black to `1.0` for white, ignoring the alpha component.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// assert_eq!(HexColor::BLACK.relative_luminance(), 0.0);
+    /// assert_eq!(HexColor::WHITE.relative_luminance(), 1.0);
+    ///
+    /// let gray = HexColor::GRAY.relative_luminance();
+    /// assert!((gray - 0.2159).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn relative_luminance(self) -> f32 {
+        let LinearRgba { r, g, b, .. } = self.to_linear();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Returns the contrast ratio between two colors as defined by [WCAG
+    /// 2.1], from `1.0` for two colors with the same luminance to `21.0` for
+    /// black and white, ignoring their alpha components.
+    ///
+    /// The ratio is symmetric, so it doesn't matter which color is the
+    /// foreground.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// assert_eq!(HexColor::BLACK.contrast_ratio(HexColor::WHITE), 21.0);
+    /// assert_eq!(HexColor::RED.contrast_ratio(HexColor::RED), 1.0);
+    ///
+    /// let ratio = HexColor::rgb(0x76, 0x76, 0x76).contrast_ratio(HexColor::WHITE);
+    /// assert!((ratio - 4.54).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(self, other: HexColor) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        // This is `(lighter + 0.05) / (darker + 0.05)`, scaled to avoid the
+        // rounding error of `0.05`.
+        (20.0 * a.max(b) + 1.0) / (20.0 * a.min(b) + 1.0)
+    }
+
+    /// Returns the highest [WCAG 2.1] conformance [`Level`] that text of
+    /// `size` in the `foreground` color meets on the `background` color, or
+    /// `None` if it doesn't even meet [`Level::AA`].
+    ///
+    /// A translucent `foreground` is composited over the `background` first,
+    /// which is treated as opaque.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Level, TextSize};
+    ///
+    /// let gray = HexColor::rgb(0x76, 0x76, 0x76);
+    /// assert_eq!(HexColor::wcag_level(gray, HexColor::WHITE, TextSize::Normal), Some(Level::AA));
+    /// assert_eq!(HexColor::wcag_level(gray, HexColor::WHITE, TextSize::Large), Some(Level::AAA));
+    ///
+    /// // Half-transparent black is gray on white:
+    /// let translucent = HexColor::rgba(0, 0, 0, 128);
+    /// assert_eq!(HexColor::wcag_level(translucent, HexColor::WHITE, TextSize::Normal), None);
+    /// assert_eq!(
+    ///     HexColor::wcag_level(translucent, HexColor::WHITE, TextSize::Large),
+    ///     Some(Level::AA),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn wcag_level(foreground: HexColor, background: HexColor, size: TextSize) -> Option<Level> {
+        let (aa, aaa) = match size {
+            TextSize::Normal => (4.5, 7.0),
+            TextSize::Large => (3.0, 4.5),
+        };
+        let ratio = foreground
+            .over_opaque(background)
+            .contrast_ratio(background);
+        if ratio >= aaa {
+            Some(Level::AAA)
+        } else if ratio >= aa {
+            Some(Level::AA)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the color of `candidates` with the highest [WCAG 2.1] contrast
+    /// ratio on the color, which is treated as an opaque background, e.g., to
+    /// pick black or white text, whichever is more readable, or `None` if
+    /// `candidates` is empty.
+    ///
+    /// Translucent candidates are composited over the background first. If
+    /// several candidates are tied, the first one of them is returned.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let text = [HexColor::BLACK, HexColor::WHITE];
+    /// assert_eq!(HexColor::rgb(255, 200, 0).best_contrast(&text), Some(&HexColor::BLACK));
+    /// assert_eq!(HexColor::rgb(0, 0, 128).best_contrast(&text), Some(&HexColor::WHITE));
+    ///
+    /// assert_eq!(HexColor::GRAY.best_contrast(&[]), None);
+    /// ```
+    #[must_use]
+    pub fn best_contrast(self, candidates: &[HexColor]) -> Option<&HexColor> {
+        let (first, rest) = candidates.split_first()?;
+        let mut best = first;
+        let mut best_ratio = best.over_opaque(self).contrast_ratio(self);
+        for candidate in rest {
+            let ratio = candidate.over_opaque(self).contrast_ratio(self);
+            if ratio > best_ratio {
+                best = candidate;
+                best_ratio = ratio;
+            }
+        }
+        Some(best)
+    }
+
+    /// Composites the color over `background`, which is treated as opaque.
+    pub(crate) const fn over_opaque(self, background: HexColor) -> HexColor {
+        self.composite(background.with_a(u8::MAX), CompositeOp::SrcOver)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Returns the color with its lightness nudged just enough for it to
+    /// have a [WCAG 2.1] contrast ratio of at least `target_ratio` on
+    /// `background`, which is treated as opaque, preserving the alpha
+    /// component.
+    ///
+    /// The lightness is changed in [`Oklch`], keeping the chroma and hue, so
+    /// that the color stays recognizably the same. It's made either lighter
+    /// or darker, whichever takes the smaller change. If the color already
+    /// has enough contrast, it's returned as is. If not even black or white
+    /// have enough contrast, whichever of them has more is returned instead.
+    ///
+    /// A translucent color is composited over the background first when
+    /// measuring the contrast.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let orange = HexColor::rgb(255, 128, 0);
+    /// assert!(orange.contrast_ratio(HexColor::WHITE) < 4.5);
+    ///
+    /// let adjusted = orange.adjust_for_contrast(HexColor::WHITE, 4.5);
+    /// assert_eq!(adjusted, HexColor::rgb(196, 85, 0));
+    /// assert!(adjusted.contrast_ratio(HexColor::WHITE) >= 4.5);
+    ///
+    /// // Neither black nor white has a contrast ratio of 7:1 on gray, but
+    /// // black comes closer:
+    /// let gray = HexColor::GRAY.adjust_for_contrast(HexColor::GRAY, 7.0);
+    /// assert_eq!(gray, HexColor::BLACK);
+    /// ```
+    #[must_use]
+    pub fn adjust_for_contrast(self, background: HexColor, target_ratio: f32) -> HexColor {
+        let contrast = |color: HexColor| color.over_opaque(background).contrast_ratio(background);
+        if contrast(self) >= target_ratio {
+            return self;
+        }
+
+        let oklch = self.to_oklch();
+        let with_lightness = |l: f32| HexColor::from_oklch(Oklch { l, ..oklch });
+
+        // Since the lightness of the color and its contrast on the background
+        // go hand in hand, the lightness that's just enough in each direction
+        // can be found with a binary search.
+        let nudge = |limit: f32| {
+            if contrast(with_lightness(limit)) < target_ratio {
+                return None;
+            }
+            let (mut near, mut far) = (oklch.l, limit);
+            while (far - near).abs() > 1e-4 {
+                let mid = f32::midpoint(near, far);
+                if contrast(with_lightness(mid)) >= target_ratio {
+                    far = mid;
+                } else {
+                    near = mid;
+                }
+            }
+            Some(far)
+        };
+
+        let lightness = match (nudge(0.0), nudge(1.0)) {
+            (Some(darker), Some(lighter)) => {
+                if oklch.l - darker <= lighter - oklch.l {
+                    darker
+                } else {
+                    lighter
+                }
+            }
+            (Some(darker), None) => darker,
+            (None, Some(lighter)) => lighter,
+            (None, None) => {
+                return if contrast(HexColor::BLACK.with_a(self.a))
+                    >= contrast(HexColor::WHITE.with_a(self.a))
+                {
+                    HexColor::BLACK.with_a(self.a)
+                } else {
+                    HexColor::WHITE.with_a(self.a)
+                };
+            }
+        };
+        with_lightness(lightness)
+    }
+}
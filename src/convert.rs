@@ -0,0 +1,247 @@
+//! Conversions from the color spaces of CSS Color Module Level 4 into sRGB,
+//! following the sample code of the specification.
+//!
+//! Unless stated otherwise, RGB triples are gamma-encoded and nominally in the
+//! range `0.0..=1.0`, but are not clamped, so out-of-gamut colors survive
+//! until they're mapped back into sRGB.
+
+use crate::math::{atan2, cbrt, cos, powf, sin, sqrt};
+use crate::{round_to_u8, HexColor};
+
+pub(crate) type Matrix = [[f32; 3]; 3];
+
+pub(crate) const XYZ_D50_TO_LINEAR_SRGB: Matrix = [
+    [3.134_136, -1.617_386, -0.490_662_22],
+    [-0.978_795_47, 1.916_254_4, 0.033_442_874],
+    [0.071_955_39, -0.228_976_76, 1.405_386_1],
+];
+
+pub(crate) const XYZ_D65_TO_LINEAR_SRGB: Matrix = [
+    [3.24097, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+pub(crate) const LINEAR_DISPLAY_P3_TO_LINEAR_SRGB: Matrix = [
+    [1.224_940_2, -0.224_940_18, 0.0],
+    [-0.042_056_955, 1.042_056_9, 0.0],
+    [-0.019_637_555, -0.078_636_04, 1.098_273_6],
+];
+
+pub(crate) const LINEAR_SRGB_TO_LINEAR_DISPLAY_P3: Matrix = [
+    [0.822_461_96, 0.177_538_04, 0.0],
+    [0.033_194_2, 0.966_805_8, 0.0],
+    [0.017_082_632, 0.072_397_44, 0.910_519_96],
+];
+
+pub(crate) const LINEAR_A98_RGB_TO_LINEAR_SRGB: Matrix = [
+    [1.398_355_7, -0.398_355_75, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, -0.042_928_99, 1.042_928_9],
+];
+
+pub(crate) const LINEAR_PROPHOTO_RGB_TO_LINEAR_SRGB: Matrix = [
+    [2.034_381, -0.727_635_8, -0.306_745_05],
+    [-0.228_825_73, 1.231_742_5, -0.002_916_809_6],
+    [-0.008_558_828, -0.153_266_7, 1.161_825_5],
+];
+
+pub(crate) const LINEAR_REC2020_TO_LINEAR_SRGB: Matrix = [
+    [1.660_491, -0.587_641_1, -0.072_849_86],
+    [-0.124_550_48, 1.132_899_9, -0.008_349_422],
+    [-0.018_150_764, -0.100_578_9, 1.118_729_7],
+];
+
+pub(crate) const LINEAR_SRGB_TO_LINEAR_REC2020: Matrix = [
+    [0.627_403_9, 0.329_283_03, 0.043_313_067],
+    [0.069_097_29, 0.919_540_4, 0.011_362_315],
+    [0.016_391_44, 0.088_013_306, 0.895_595_25],
+];
+
+pub(crate) fn multiply(matrix: &Matrix, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    matrix.map(|[a, b, c]| a * x + b * y + c * z)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Transfer functions
+////////////////////////////////////////////////////////////////////////////////
+
+// Every transfer function is extended to negative values by symmetry, just
+// like in the specification.
+
+/// Decodes a gamma-encoded sRGB (or Display P3) channel.
+pub(crate) fn srgb_to_linear(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.040_45 {
+        c / 12.92
+    } else {
+        powf((abs + 0.055) / 1.055, 2.4).copysign(c)
+    }
+}
+
+/// Gamma-encodes a linear-light sRGB (or Display P3) channel.
+pub(crate) fn linear_to_srgb(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        (1.055 * powf(abs, 1.0 / 2.4) - 0.055).copysign(c)
+    }
+}
+
+pub(crate) fn a98_rgb_to_linear(c: f32) -> f32 {
+    powf(c.abs(), 563.0 / 256.0).copysign(c)
+}
+
+pub(crate) fn prophoto_rgb_to_linear(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 16.0 / 512.0 {
+        c / 16.0
+    } else {
+        powf(abs, 1.8).copysign(c)
+    }
+}
+
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+pub(crate) fn rec2020_to_linear(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs < REC2020_BETA * 4.5 {
+        c / 4.5
+    } else {
+        powf((abs + REC2020_ALPHA - 1.0) / REC2020_ALPHA, 1.0 / 0.45).copysign(c)
+    }
+}
+
+pub(crate) fn linear_to_rec2020(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs < REC2020_BETA {
+        c * 4.5
+    } else {
+        (REC2020_ALPHA * powf(abs, 0.45) - (REC2020_ALPHA - 1.0)).copysign(c)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Oklab
+////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn oklab_to_linear_srgb([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_4 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+pub(crate) fn linear_srgb_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = cbrt(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+    let m = cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+    let s = cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Gamut mapping
+////////////////////////////////////////////////////////////////////////////////
+
+fn in_srgb_gamut(rgb: [f32; 3]) -> bool {
+    rgb.iter().all(|c| (0.0..=1.0).contains(c))
+}
+
+fn clip(rgb: [f32; 3]) -> [f32; 3] {
+    rgb.map(|c| c.clamp(0.0, 1.0))
+}
+
+/// The Euclidean distance between two sRGB colors in Oklab.
+fn delta_eok(x: [f32; 3], y: [f32; 3]) -> f32 {
+    let [l1, a1, b1] = linear_srgb_to_oklab(x.map(srgb_to_linear));
+    let [l2, a2, b2] = linear_srgb_to_oklab(y.map(srgb_to_linear));
+    let (dl, da, db) = (l1 - l2, a1 - a2, b1 - b2);
+    sqrt(dl * dl + da * da + db * db)
+}
+
+/// Maps a linear-light sRGB color into the sRGB gamut with the [CSS gamut
+/// mapping algorithm], which reduces the chroma in Oklch until clipping the
+/// color is (just) imperceptible, and gamma-encodes it.
+///
+/// [CSS gamut mapping algorithm]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+pub(crate) fn gamut_map_linear_srgb(rgb: [f32; 3]) -> [f32; 3] {
+    const JND: f32 = 0.02;
+    const EPSILON: f32 = 0.0001;
+
+    let origin = rgb.map(linear_to_srgb);
+    if in_srgb_gamut(origin) {
+        return origin;
+    }
+
+    let [lightness, a, b] = linear_srgb_to_oklab(rgb);
+    if lightness >= 1.0 {
+        return [1.0; 3];
+    }
+    if lightness <= 0.0 {
+        return [0.0; 3];
+    }
+    let hue = atan2(b, a);
+    let with_chroma = |chroma: f32| {
+        let oklab = [lightness, chroma * cos(hue), chroma * sin(hue)];
+        oklab_to_linear_srgb(oklab).map(linear_to_srgb)
+    };
+
+    let mut clipped = clip(origin);
+    if delta_eok(clipped, origin) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = sqrt(a * a + b * b);
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = f32::midpoint(min, max);
+        let current = with_chroma(chroma);
+        if min_in_gamut && in_srgb_gamut(current) {
+            min = chroma;
+            continue;
+        }
+
+        clipped = clip(current);
+        let delta_e = delta_eok(clipped, current);
+        if delta_e < JND {
+            if JND - delta_e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+/// Clips a linear-light sRGB color into the sRGB gamut, i.e., clamps each
+/// channel independently, and gamma-encodes it.
+pub(crate) fn clip_linear_srgb(rgb: [f32; 3]) -> [f32; 3] {
+    clip(rgb).map(linear_to_srgb)
+}
+
+/// Rounds a gamma-encoded sRGB color to the nearest `HexColor`, clamping any
+/// channels out of range.
+pub(crate) fn srgb_to_hex_color(rgb: [f32; 3], alpha: u8) -> HexColor {
+    let [r, g, b] = rgb.map(|c| round_to_u8(c * 255.0));
+    HexColor::rgba(r, g, b, alpha)
+}
@@ -0,0 +1,812 @@
+use core::fmt;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::convert::{self, Matrix};
+use crate::{round_to_u8, HexColor, ParseHexColorError};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{Hsl, Hwb, Lab, Lch, Oklab, Oklch, ParseOptions, WhitePoint};
+
+impl HexColor {
+    /// Parses a CSS `rgb()` or `rgba()` function into a `HexColor`.
+    ///
+    /// Both the modern, space-separated syntax from [CSS Color Module Level
+    /// 4] and the legacy, comma-separated syntax are accepted. The two
+    /// function names are aliases of each other, and are case-insensitive.
+    ///
+    /// * Each channel is a `<number>` in the range `0..=255`, a
+    ///   `<percentage>`, or `none` (only in the modern syntax, where it's
+    ///   treated as `0`).
+    /// * The optional alpha channel follows a `/` (or a `,` in the legacy
+    ///   syntax) and is a `<number>` in the range `0..=1`, a `<percentage>`,
+    ///   or `none`.
+    /// * The legacy syntax doesn't allow mixing `<number>` and `<percentage>`
+    ///   channels.
+    ///
+    /// Values out of range are clamped, and every channel is rounded to the
+    /// nearest `u8`. Leading and trailing whitespace is ignored.
+    ///
+    /// To parse any other kind of CSS color, use [`parse_css`] instead.
+    ///
+    /// [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/#rgb-functions
+    /// [`parse_css`]: HexColor::parse_css
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseCssColorError`] describing why, and where, the input
+    /// isn't a valid `rgb()` or `rgba()` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// # fn main() -> Result<(), hex_color::ParseCssColorError> {
+    /// let dodger_blue = HexColor::rgb(30, 144, 255);
+    /// assert_eq!(HexColor::parse_css_rgb("rgb(30 144 255)")?, dodger_blue);
+    /// assert_eq!(HexColor::parse_css_rgb("rgb(30, 144, 255)")?, dodger_blue);
+    ///
+    /// let translucent = HexColor::rgba(30, 144, 255, 128);
+    /// assert_eq!(
+    ///     HexColor::parse_css_rgb("rgb(30 144 255 / 50%)")?,
+    ///     translucent,
+    /// );
+    /// assert_eq!(
+    ///     HexColor::parse_css_rgb("rgba(30, 144, 255, 0.5)")?,
+    ///     translucent,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_css_rgb("RGB(100% none 300)")?,
+    ///     HexColor::MAGENTA,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_css_rgb(s: &str) -> Result<HexColor, ParseCssColorError> {
+        let function = Function::parse(s)?;
+        if !function.name.eq_ignore_ascii_case("rgb") && !function.name.eq_ignore_ascii_case("rgba")
+        {
+            return Err(ParseCssColorError::UnknownFunction);
+        }
+        parse_rgb(function.arguments)
+    }
+
+    /// Parses any CSS color into a `HexColor`.
+    ///
+    /// Every `<color>` of [CSS Color Module Level 4] that doesn't depend on
+    /// the context it's used in is accepted:
+    ///
+    /// * Hexadecimal colors, following the rules of [`parse`].
+    /// * Named colors, e.g., `rebeccapurple`, and `transparent`, as recognized
+    ///   by [`HexColor::from_name`].
+    /// * `rgb()` and `rgba()`, following the rules of [`parse_css_rgb`].
+    /// * `hsl()` and `hsla()`, in both the modern and legacy syntax.
+    /// * `hwb()`, `lab()`, `lch()`, `oklab()`, and `oklch()`.
+    /// * `color()` in any of the predefined color spaces: `srgb`,
+    ///   `srgb-linear`, `display-p3`, `a98-rgb`, `prophoto-rgb`, `rec2020`,
+    ///   `xyz`, `xyz-d50`, and `xyz-d65`.
+    ///
+    /// Names, functions, units, and color spaces are all case-insensitive,
+    /// and leading and trailing whitespace is ignored.
+    ///
+    /// Out-of-range channels of `rgb()`, `hsl()`, and `hwb()` colors are
+    /// clamped. Every other color, including one from `color()` in the
+    /// `srgb` or `srgb-linear` color space, that's outside of the sRGB gamut
+    /// is mapped into it with the [CSS gamut mapping algorithm], which
+    /// reduces its chroma instead of clipping its hue. Each channel is then
+    /// rounded to the nearest `u8`.
+    ///
+    /// [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/
+    /// [CSS gamut mapping algorithm]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+    /// [`parse`]: HexColor::parse
+    /// [`parse_css_rgb`]: HexColor::parse_css_rgb
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseCssColorError`] describing why, and where, the input
+    /// isn't a valid CSS color. Malformed hexadecimal colors are reported as
+    /// a [`ParseCssColorError::Hex`] wrapping the [`ParseHexColorError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// # fn main() -> Result<(), hex_color::ParseCssColorError> {
+    /// let rebecca_purple = HexColor::rgb(102, 51, 153);
+    /// assert_eq!(HexColor::parse_css("rebeccapurple")?, rebecca_purple);
+    /// assert_eq!(HexColor::parse_css("#663399")?, rebecca_purple);
+    /// assert_eq!(HexColor::parse_css("rgb(102 51 153)")?, rebecca_purple);
+    /// assert_eq!(HexColor::parse_css("hsl(270 50% 40%)")?, rebecca_purple);
+    /// assert_eq!(HexColor::parse_css("hwb(270 20% 40%)")?, rebecca_purple);
+    /// assert_eq!(
+    ///     HexColor::parse_css("lab(32.39% 38.43 -47.69)")?,
+    ///     rebecca_purple,
+    /// );
+    /// assert_eq!(
+    ///     HexColor::parse_css("oklch(44.03% 0.1603 303.37)")?,
+    ///     rebecca_purple,
+    /// );
+    /// assert_eq!(
+    ///     HexColor::parse_css("color(srgb 0.4 0.2 0.6)")?,
+    ///     rebecca_purple,
+    /// );
+    ///
+    /// // Pure Display P3 red is outside of the sRGB gamut, so its chroma is
+    /// // reduced, keeping its lightness and hue, rather than simply clipped:
+    /// assert_eq!(
+    ///     HexColor::parse_css("color(display-p3 1 0 0 / 50%)")?,
+    ///     HexColor::rgba(255, 11, 12, 128),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn parse_css(s: &str) -> Result<HexColor, ParseCssColorError> {
+        const FUNCTIONS: [(&str, ParseFunction); 10] = [
+            ("rgb", parse_rgb),
+            ("rgba", parse_rgb),
+            ("hsl", parse_hsl),
+            ("hsla", parse_hsl),
+            ("hwb", parse_hwb),
+            ("lab", parse_lab),
+            ("lch", parse_lch),
+            ("oklab", parse_oklab),
+            ("oklch", parse_oklch),
+            ("color", parse_color),
+        ];
+
+        let trimmed = s.trim_matches(is_whitespace);
+        if trimmed.starts_with('#') {
+            let options = ParseOptions::new().trim_whitespace(true);
+            return HexColor::parse_with(s, options).map_err(ParseCssColorError::Hex);
+        }
+        if trimmed.is_empty() || trimmed.contains('(') {
+            let function = Function::parse(s)?;
+            let (_, parse) = FUNCTIONS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(function.name))
+                .ok_or(ParseCssColorError::UnknownFunction)?;
+            return parse(function.arguments);
+        }
+
+        HexColor::from_name(trimmed).ok_or(ParseCssColorError::UnknownName)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Functions
+////////////////////////////////////////////////////////////////////////////////
+
+/// A CSS function call, e.g., `rgb(0 0 0)`, split into its name and its
+/// (tokenized) arguments.
+struct Function<'a> {
+    name: &'a str,
+    arguments: Tokens<'a>,
+}
+
+impl<'a> Function<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseCssColorError> {
+        let trimmed = s.trim_start_matches(is_whitespace);
+        let start = s.len() - trimmed.len();
+        let trimmed = trimmed.trim_end_matches(is_whitespace);
+        if trimmed.is_empty() {
+            return Err(ParseCssColorError::Empty);
+        }
+
+        let Some(open) = trimmed.find('(') else {
+            return Err(ParseCssColorError::UnknownFunction);
+        };
+        let name = &trimmed[..open];
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+            return Err(ParseCssColorError::UnknownFunction);
+        }
+        let Some(body) = trimmed[open + 1..].strip_suffix(')') else {
+            return Err(ParseCssColorError::UnclosedFunction);
+        };
+
+        Ok(Function {
+            name,
+            arguments: Tokens::new(s, start + open + 1, start + open + 1 + body.len()),
+        })
+    }
+}
+
+/// Parses the arguments of a specific color function.
+#[cfg(any(feature = "std", feature = "libm"))]
+type ParseFunction = fn(Tokens) -> Result<HexColor, ParseCssColorError>;
+
+/// An argument of a color function, along with its index in the input.
+type Argument<'a> = (usize, Token<'a>);
+
+/// The channels of a color function, before they're interpreted by the
+/// function, and its alpha.
+struct Arguments<'a> {
+    channels: [Argument<'a>; 3],
+    alpha: u8,
+    legacy: bool,
+}
+
+fn parse_rgb(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels,
+        alpha,
+        legacy,
+    } = arguments.parse_arguments(true)?;
+
+    let mut rgb = [0; 3];
+    for (value, argument) in rgb.iter_mut().zip(channels) {
+        if legacy && !argument.1.is_same_kind(channels[0].1) {
+            return Err(ParseCssColorError::InvalidArgument { index: argument.0 });
+        }
+        *value = round_to_u8(number_or_percentage(argument, 255.0)?);
+    }
+
+    let [r, g, b] = rgb;
+    Ok(HexColor::rgba(r, g, b, alpha))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_hsl(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [h, s, l],
+        alpha,
+        legacy,
+    } = arguments.parse_arguments(true)?;
+
+    for (index, token) in [s, l] {
+        if legacy && !matches!(token, Token::Percentage(_)) {
+            return Err(ParseCssColorError::InvalidArgument { index });
+        }
+    }
+    let hsl = Hsl {
+        h: hue(h)?,
+        s: number_or_percentage(s, 100.0)? / 100.0,
+        l: number_or_percentage(l, 100.0)? / 100.0,
+        a: alpha,
+    };
+    Ok(HexColor::from_hsl(hsl))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_hwb(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [h, w, b],
+        alpha,
+        ..
+    } = arguments.parse_arguments(false)?;
+
+    let hwb = Hwb {
+        h: hue(h)?,
+        w: number_or_percentage(w, 100.0)? / 100.0,
+        b: number_or_percentage(b, 100.0)? / 100.0,
+        a: alpha,
+    };
+    Ok(HexColor::from_hwb(hwb))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_lab(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [l, a, b],
+        alpha,
+        ..
+    } = arguments.parse_arguments(false)?;
+
+    let lab = Lab {
+        l: number_or_percentage(l, 100.0)?.clamp(0.0, 100.0),
+        a: number_or_percentage(a, 125.0)?,
+        b: number_or_percentage(b, 125.0)?,
+        alpha,
+    };
+    Ok(from_lab(lab))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_lch(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [l, c, h],
+        alpha,
+        ..
+    } = arguments.parse_arguments(false)?;
+
+    let lightness = number_or_percentage(l, 100.0)?.clamp(0.0, 100.0);
+    let chroma = number_or_percentage(c, 150.0)?.max(0.0);
+    let lch = Lch {
+        l: lightness,
+        c: chroma,
+        h: hue(h)?,
+        alpha,
+    };
+    Ok(from_lab(lch.into()))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_oklab(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [l, a, b],
+        alpha,
+        ..
+    } = arguments.parse_arguments(false)?;
+
+    let oklab = Oklab {
+        l: number_or_percentage(l, 1.0)?.clamp(0.0, 1.0),
+        a: number_or_percentage(a, 0.4)?,
+        b: number_or_percentage(b, 0.4)?,
+        alpha,
+    };
+    Ok(HexColor::from_oklab(oklab))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_oklch(arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    let Arguments {
+        channels: [l, c, h],
+        alpha,
+        ..
+    } = arguments.parse_arguments(false)?;
+
+    let oklch = Oklch {
+        l: number_or_percentage(l, 1.0)?.clamp(0.0, 1.0),
+        c: number_or_percentage(c, 0.4)?.max(0.0),
+        h: hue(h)?,
+        alpha,
+    };
+    Ok(HexColor::from_oklch(oklch))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn parse_color(mut arguments: Tokens) -> Result<HexColor, ParseCssColorError> {
+    // A transfer function to linear light and a matrix into linear sRGB; the
+    // XYZ spaces are already linear.
+    type ColorSpace = (Option<fn(f32) -> f32>, Option<&'static Matrix>);
+
+    const COLOR_SPACES: [(&str, ColorSpace); 9] = [
+        ("srgb", (Some(convert::srgb_to_linear), None)),
+        ("srgb-linear", (None, None)),
+        (
+            "display-p3",
+            (
+                Some(convert::srgb_to_linear),
+                Some(&convert::LINEAR_DISPLAY_P3_TO_LINEAR_SRGB),
+            ),
+        ),
+        (
+            "a98-rgb",
+            (
+                Some(convert::a98_rgb_to_linear),
+                Some(&convert::LINEAR_A98_RGB_TO_LINEAR_SRGB),
+            ),
+        ),
+        (
+            "prophoto-rgb",
+            (
+                Some(convert::prophoto_rgb_to_linear),
+                Some(&convert::LINEAR_PROPHOTO_RGB_TO_LINEAR_SRGB),
+            ),
+        ),
+        (
+            "rec2020",
+            (
+                Some(convert::rec2020_to_linear),
+                Some(&convert::LINEAR_REC2020_TO_LINEAR_SRGB),
+            ),
+        ),
+        ("xyz", (None, Some(&convert::XYZ_D65_TO_LINEAR_SRGB))),
+        ("xyz-d50", (None, Some(&convert::XYZ_D50_TO_LINEAR_SRGB))),
+        ("xyz-d65", (None, Some(&convert::XYZ_D65_TO_LINEAR_SRGB))),
+    ];
+
+    let (to_linear, matrix) = match arguments.next()? {
+        Some((index, Token::Ident(ident))) => COLOR_SPACES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(ident))
+            .map(|&(_, color_space)| color_space)
+            .ok_or(ParseCssColorError::InvalidArgument { index })?,
+        Some((index, _)) => return Err(ParseCssColorError::InvalidArgument { index }),
+        None => return Err(ParseCssColorError::MissingArgument),
+    };
+    let Arguments {
+        channels, alpha, ..
+    } = arguments.parse_arguments(false)?;
+
+    let mut rgb = [0.0; 3];
+    for (value, argument) in rgb.iter_mut().zip(channels) {
+        *value = number_or_percentage(argument, 1.0)?;
+    }
+    if let Some(to_linear) = to_linear {
+        rgb = rgb.map(to_linear);
+    }
+    if let Some(matrix) = matrix {
+        rgb = convert::multiply(matrix, rgb);
+    }
+    Ok(from_linear_srgb(rgb, alpha))
+}
+
+/// Resolves a `<number>`, `<percentage>` (where `100%` is `hundred_percent`),
+/// or `none`.
+fn number_or_percentage(
+    (index, token): Argument,
+    hundred_percent: f32,
+) -> Result<f32, ParseCssColorError> {
+    match token {
+        Token::Number(n) => Ok(n),
+        Token::Percentage(p) => Ok(p / 100.0 * hundred_percent),
+        Token::Ident(ident) if ident.eq_ignore_ascii_case("none") => Ok(0.0),
+        _ => Err(ParseCssColorError::InvalidArgument { index }),
+    }
+}
+
+/// Resolves a `<hue>`, i.e., a `<number>` of degrees or an `<angle>`, or
+/// `none` into degrees.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn hue((index, token): Argument) -> Result<f32, ParseCssColorError> {
+    const UNITS: [(&str, f32); 4] = [
+        ("deg", 1.0),
+        ("grad", 360.0 / 400.0),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    match token {
+        Token::Number(n) => Ok(n),
+        Token::Dimension(n, unit) => UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|(_, degrees)| n * degrees)
+            .ok_or(ParseCssColorError::InvalidArgument { index }),
+        Token::Ident(ident) if ident.eq_ignore_ascii_case("none") => Ok(0.0),
+        _ => Err(ParseCssColorError::InvalidArgument { index }),
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn from_lab(lab: Lab) -> HexColor {
+    let xyz = lab.to_xyz(WhitePoint::D50).to_array();
+    from_linear_srgb(
+        convert::multiply(&convert::XYZ_D50_TO_LINEAR_SRGB, xyz),
+        lab.alpha,
+    )
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn from_linear_srgb(rgb: [f32; 3], alpha: u8) -> HexColor {
+    convert::srgb_to_hex_color(convert::gamut_map_linear_srgb(rgb), alpha)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tokens
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Token<'a> {
+    Number(f32),
+    Percentage(f32),
+    Dimension(f32, &'a str),
+    Ident(&'a str),
+    Comma,
+    Slash,
+}
+
+impl Token<'_> {
+    fn is_same_kind(self, other: Token) -> bool {
+        core::mem::discriminant(&self) == core::mem::discriminant(&other)
+    }
+}
+
+/// A tokenizer over the arguments of a [`Function`]. All indices are byte
+/// offsets into the original input, so they can be reported in errors as-is.
+#[derive(Clone)]
+struct Tokens<'a> {
+    input: &'a str,
+    position: usize,
+    end: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(input: &'a str, start: usize, end: usize) -> Self {
+        Tokens {
+            input,
+            position: start,
+            end,
+        }
+    }
+
+    fn peek(&self) -> Result<Option<Argument<'a>>, ParseCssColorError> {
+        self.clone().next()
+    }
+
+    fn next(&mut self) -> Result<Option<Argument<'a>>, ParseCssColorError> {
+        let bytes = &self.input.as_bytes()[..self.end];
+        while self.position < self.end && is_whitespace(char::from(bytes[self.position])) {
+            self.position += 1;
+        }
+
+        let start = self.position;
+        let Some(&byte) = bytes.get(start) else {
+            return Ok(None);
+        };
+        let token = match byte {
+            b',' => {
+                self.position += 1;
+                Token::Comma
+            }
+            b'/' => {
+                self.position += 1;
+                Token::Slash
+            }
+            _ => {
+                let number = number_len(&bytes[start..]);
+                if number > 0 {
+                    self.position += number;
+                    // `number_len` only accepts valid CSS numbers, which are a
+                    // subset of what `f32` parses.
+                    let n: f32 = self.input[start..start + number].parse().unwrap_or(0.0);
+                    let unit = ident_len(&bytes[self.position..]);
+                    if bytes.get(self.position) == Some(&b'%') {
+                        self.position += 1;
+                        Token::Percentage(n)
+                    } else if unit > 0 {
+                        self.position += unit;
+                        Token::Dimension(n, &self.input[start + number..self.position])
+                    } else {
+                        Token::Number(n)
+                    }
+                } else {
+                    let ident = ident_len(&bytes[start..]);
+                    if ident == 0 {
+                        return Err(self.unexpected_character(start));
+                    }
+                    self.position += ident;
+                    Token::Ident(&self.input[start..self.position])
+                }
+            }
+        };
+
+        // Tokens must be separated by something, e.g., `1 2` or `1/2`, but not
+        // `12%3` or `1.2.3`.
+        let next = bytes.get(self.position).copied();
+        if !matches!(token, Token::Comma | Token::Slash)
+            && next.is_some_and(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'%')
+        {
+            return Err(self.unexpected_character(self.position));
+        }
+        Ok(Some((start, token)))
+    }
+
+    fn unexpected_character(&self, index: usize) -> ParseCssColorError {
+        let found = self.input[index..].chars().next().unwrap_or_default();
+        ParseCssColorError::UnexpectedCharacter { index, found }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ParseCssColorError> {
+        match self.next()? {
+            Some((_, token)) if token == expected => Ok(()),
+            Some((index, _)) => Err(ParseCssColorError::InvalidArgument { index }),
+            None => Err(ParseCssColorError::MissingArgument),
+        }
+    }
+
+    fn expect_argument(&mut self) -> Result<Argument<'a>, ParseCssColorError> {
+        match self.next()? {
+            Some((index, Token::Comma | Token::Slash)) => {
+                Err(ParseCssColorError::InvalidArgument { index })
+            }
+            Some(argument) => Ok(argument),
+            None => Err(ParseCssColorError::MissingArgument),
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), ParseCssColorError> {
+        match self.next()? {
+            Some((index, _)) => Err(ParseCssColorError::InvalidArgument { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses the remaining arguments of a color function: three channels
+    /// and an optional alpha, separated by whitespace and a `/`, or, if
+    /// `allow_legacy` is set, by commas.
+    ///
+    /// `none` is never allowed in the legacy syntax, which is otherwise up to
+    /// the caller to validate.
+    fn parse_arguments(mut self, allow_legacy: bool) -> Result<Arguments<'a>, ParseCssColorError> {
+        let first = self.expect_argument()?;
+        let legacy = allow_legacy && matches!(self.peek()?, Some((_, Token::Comma)));
+
+        let mut channels = [first; 3];
+        for channel in &mut channels[1..] {
+            if legacy {
+                self.expect(Token::Comma)?;
+            }
+            *channel = self.expect_argument()?;
+        }
+        if legacy {
+            if let Some(&(index, _)) = channels.iter().find(|(_, t)| matches!(t, Token::Ident(_))) {
+                return Err(ParseCssColorError::InvalidArgument { index });
+            }
+        }
+        let alpha = self.parse_alpha(legacy)?;
+        self.expect_end()?;
+
+        Ok(Arguments {
+            channels,
+            alpha,
+            legacy,
+        })
+    }
+
+    /// Parses the optional `/ <alpha-value>` (or `, <alpha-value>` in the
+    /// legacy syntax) at the end of a color function, defaulting to opaque.
+    fn parse_alpha(&mut self, legacy: bool) -> Result<u8, ParseCssColorError> {
+        let separator = if legacy { Token::Comma } else { Token::Slash };
+        match self.peek()? {
+            Some((_, token)) if token == separator => {
+                self.next()?;
+            }
+            _ => return Ok(u8::MAX),
+        }
+
+        let argument = self.expect_argument()?;
+        if legacy && matches!(argument.1, Token::Ident(_)) {
+            return Err(ParseCssColorError::InvalidArgument { index: argument.0 });
+        }
+        Ok(round_to_u8(number_or_percentage(argument, 1.0)? * 255.0))
+    }
+}
+
+/// CSS only considers these five characters whitespace.
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '\x0C')
+}
+
+/// Returns the length of the CSS `<number>` at the start of `bytes`, or `0` if
+/// there isn't one.
+fn number_len(bytes: &[u8]) -> usize {
+    fn digits(bytes: &[u8], from: usize) -> usize {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    let mut len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let integer = digits(bytes, len);
+    len += integer;
+    let mut fraction = 0;
+    if bytes.get(len) == Some(&b'.') {
+        fraction = digits(bytes, len + 1);
+        if fraction > 0 {
+            len += 1 + fraction;
+        }
+    }
+    if integer == 0 && fraction == 0 {
+        return 0;
+    }
+    if matches!(bytes.get(len), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(len + 1), Some(b'+' | b'-')));
+        let exponent = digits(bytes, len + 1 + sign);
+        if exponent > 0 {
+            len += 1 + sign + exponent;
+        }
+    }
+    len
+}
+
+/// Returns the length of the (ASCII) CSS identifier at the start of `bytes`,
+/// or `0` if there isn't one.
+fn ident_len(bytes: &[u8]) -> usize {
+    let start = match bytes {
+        [b'-', b'a'..=b'z' | b'A'..=b'Z' | b'_', ..] => 2,
+        [b'a'..=b'z' | b'A'..=b'Z' | b'_', ..] => 1,
+        _ => return 0,
+    };
+    start
+        + bytes[start..]
+            .iter()
+            .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            .count()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////
+
+/// An error which can be returned when parsing a CSS color.
+///
+/// Like [`ParseHexColorError`], every index is a byte offset into the original
+/// input.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, ParseCssColorError};
+///
+/// assert_eq!(
+///     HexColor::parse_css_rgb("rgb(0, 0 0)"),
+///     Err(ParseCssColorError::InvalidArgument { index: 9 }),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseCssColorError {
+    /// The input was empty.
+    Empty,
+    /// The input looked like a hexadecimal color, but couldn't be parsed as
+    /// one.
+    Hex(ParseHexColorError),
+    /// The input isn't a known color keyword, e.g., `blue` or `transparent`.
+    UnknownName,
+    /// The input isn't a function this parser understands.
+    UnknownFunction,
+    /// The function is missing its closing parenthesis.
+    UnclosedFunction,
+    /// A character that can't begin or end any CSS token was found.
+    UnexpectedCharacter {
+        /// The byte offset of the character in the input.
+        index: usize,
+        /// The unexpected character itself.
+        found: char,
+    },
+    /// An argument is of the wrong type, in the wrong place, or is extraneous.
+    InvalidArgument {
+        /// The byte offset of the argument in the input.
+        index: usize,
+    },
+    /// The function ended before all of its arguments were given.
+    MissingArgument,
+}
+
+impl fmt::Display for ParseCssColorError {
+    /// The message of a [`ParseCssColorError::Hex`] doesn't repeat the
+    /// [`ParseHexColorError`], which is its [source] instead.
+    ///
+    /// [source]: std::error::Error::source
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use hex_color::HexColor;
+    ///
+    /// let err = HexColor::parse_css("#00FF0G").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid hexadecimal CSS color");
+    /// assert_eq!(
+    ///     err.source().unwrap().to_string(),
+    ///     "invalid hexadecimal digit 'G' at index 6",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseCssColorError::Empty => f.write_str("cannot parse CSS color from empty string"),
+            ParseCssColorError::Hex(_) => f.write_str("invalid hexadecimal CSS color"),
+            ParseCssColorError::UnknownName => f.write_str("unknown CSS color name"),
+            ParseCssColorError::UnknownFunction => f.write_str("unknown CSS color function"),
+            ParseCssColorError::UnclosedFunction => {
+                f.write_str("CSS color function is missing a closing `)`")
+            }
+            ParseCssColorError::UnexpectedCharacter { index, found } => {
+                write!(f, "unexpected character {found:?} at index {index}")
+            }
+            ParseCssColorError::InvalidArgument { index } => {
+                write!(f, "invalid CSS color function argument at index {index}")
+            }
+            ParseCssColorError::MissingArgument => {
+                f.write_str("CSS color function is missing an argument")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseCssColorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseCssColorError::Hex(err) => Some(err),
+            _ => None,
+        }
+    }
+}
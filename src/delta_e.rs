@@ -0,0 +1,287 @@
+//! Color difference metrics, i.e., measures of how different two colors look,
+//! as opposed to whether they're exactly equal.
+//!
+//! Every metric is a flavor of "ΔE" (delta E), where a difference of about
+//! `1.0` is the smallest one that's noticeable, except for
+//! [`Metric::Oklab`], whose scale is a hundred times smaller. The easiest way
+//! to use them is [`HexColor::distance`], but the underlying functions are
+//! also available for colors that are already in [`Lab`] or [`Oklab`].
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_color::{HexColor, Metric};
+//!
+//! // Two shades of blue that a user submitted twice, more or less:
+//! let a = HexColor::rgb(30, 144, 255);
+//! let b = HexColor::rgb(28, 145, 252);
+//! assert!(a != b);
+//! assert!(a.distance(b, Metric::Ciede2000) < 1.0);
+//! ```
+
+use crate::math::{atan2, cos, exp, sin, sqrt};
+use crate::{normalize_hue, HexColor, Lab, Oklab, WhitePoint};
+
+/// A color difference metric to measure with [`HexColor::distance`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Metric {
+    /// CIE76, i.e., the Euclidean distance in [`Lab`]. It's the simplest
+    /// metric, but overstates the difference between saturated colors. See
+    /// [`cie76`].
+    Cie76,
+    /// CIE94, which corrects CIE76 for chroma and hue, with the constants for
+    /// graphic arts. See [`cie94`].
+    Cie94,
+    /// CIEDE2000, the most accurate of the CIE metrics, which also corrects
+    /// for lightness and for the blue region. See [`ciede2000`].
+    #[default]
+    Ciede2000,
+    /// ΔEOK, i.e., the Euclidean distance in [`Oklab`], which is about as
+    /// accurate as CIEDE2000, but much simpler. See [`oklab`].
+    Oklab,
+}
+
+impl HexColor {
+    /// Returns how different the color looks from `other` according to
+    /// `metric`, ignoring the alpha components.
+    ///
+    /// The CIE metrics compare the colors in [`Lab`] relative to
+    /// [`WhitePoint::D50`], like CSS does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Metric};
+    ///
+    /// let red = HexColor::RED;
+    /// let crimson = HexColor::rgb(220, 20, 60);
+    ///
+    /// assert!((red.distance(crimson, Metric::Cie76) - 36.3).abs() < 0.1);
+    /// assert!((red.distance(crimson, Metric::Cie94) - 11.9).abs() < 0.1);
+    /// assert!((red.distance(crimson, Metric::Ciede2000) - 13.9).abs() < 0.1);
+    /// assert!((red.distance(crimson, Metric::Oklab) - 0.08).abs() < 0.01);
+    ///
+    /// assert_eq!(red.distance(red, Metric::Ciede2000), 0.0);
+    /// ```
+    #[must_use]
+    pub fn distance(self, other: HexColor, metric: Metric) -> f32 {
+        let lab = |color: HexColor| color.to_lab(WhitePoint::D50);
+        match metric {
+            Metric::Cie76 => cie76(lab(self), lab(other)),
+            Metric::Cie94 => cie94(lab(self), lab(other)),
+            Metric::Ciede2000 => ciede2000(lab(self), lab(other)),
+            Metric::Oklab => oklab(self.to_oklab(), other.to_oklab()),
+        }
+    }
+}
+
+/// Returns the CIE76 color difference between two [`Lab`] colors, i.e., the
+/// Euclidean distance between them.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::delta_e;
+/// use hex_color::Lab;
+///
+/// let distance = delta_e::cie76(Lab::new(50.0, 0.0, 0.0), Lab::new(53.0, 4.0, 0.0));
+/// assert_eq!(distance, 5.0);
+/// ```
+#[must_use]
+pub fn cie76(reference: Lab, sample: Lab) -> f32 {
+    let (dl, da, db) = (
+        reference.l - sample.l,
+        reference.a - sample.a,
+        reference.b - sample.b,
+    );
+    sqrt(dl * dl + da * da + db * db)
+}
+
+/// Returns the CIE94 color difference between two [`Lab`] colors, with the
+/// constants for graphic arts (`kL = 1`, `K1 = 0.045`, `K2 = 0.015`).
+///
+/// CIE94 isn't symmetric: the chroma of `reference` weighs the differences in
+/// chroma and hue.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::delta_e;
+/// use hex_color::Lab;
+///
+/// let gray = Lab::new(50.0, 0.0, 0.0);
+/// let red = Lab::new(50.0, 60.0, 0.0);
+///
+/// assert_eq!(delta_e::cie94(gray, red), 60.0);
+/// assert!((delta_e::cie94(red, gray) - 16.2).abs() < 0.1);
+/// ```
+#[must_use]
+pub fn cie94(reference: Lab, sample: Lab) -> f32 {
+    const K1: f32 = 0.045;
+    const K2: f32 = 0.015;
+
+    let c1 = sqrt(reference.a * reference.a + reference.b * reference.b);
+    let c2 = sqrt(sample.a * sample.a + sample.b * sample.b);
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let (da, db) = (reference.a - sample.a, reference.b - sample.b);
+    // Rounding can make this ever so slightly negative.
+    let dh_squared = (da * da + db * db - dc * dc).max(0.0);
+
+    let sc = 1.0 + K1 * c1;
+    let sh = 1.0 + K2 * c1;
+    sqrt(dl * dl + (dc / sc) * (dc / sc) + dh_squared / (sh * sh))
+}
+
+/// Returns the CIEDE2000 color difference between two [`Lab`] colors, with
+/// all of the parametric factors, `kL`, `kC`, and `kH`, equal to `1`.
+///
+/// The implementation follows [Sharma, Wu, and Dalal (2005)], including their
+/// conventions for hues that are undefined or exactly opposite.
+///
+/// [Sharma, Wu, and Dalal (2005)]: https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/
+///
+/// # Examples
+///
+/// The test data of Sharma, Wu, and Dalal:
+///
+/// ```
+/// use hex_color::delta_e;
+/// use hex_color::Lab;
+///
+/// let pairs = [
+///     (Lab::new(50.0000, 2.6772, -79.7751), Lab::new(50.0000, 0.0000, -82.7485), 2.0425),
+///     (Lab::new(50.0000, 3.1571, -77.2803), Lab::new(50.0000, 0.0000, -82.7485), 2.8615),
+///     (Lab::new(50.0000, 2.8361, -74.0200), Lab::new(50.0000, 0.0000, -82.7485), 3.4412),
+///     (Lab::new(50.0000, -1.3802, -84.2814), Lab::new(50.0000, 0.0000, -82.7485), 1.0000),
+///     (Lab::new(50.0000, -1.1848, -84.8006), Lab::new(50.0000, 0.0000, -82.7485), 1.0000),
+///     (Lab::new(50.0000, -0.9009, -85.5211), Lab::new(50.0000, 0.0000, -82.7485), 1.0000),
+///     (Lab::new(50.0000, 0.0000, 0.0000), Lab::new(50.0000, -1.0000, 2.0000), 2.3669),
+///     (Lab::new(50.0000, -1.0000, 2.0000), Lab::new(50.0000, 0.0000, 0.0000), 2.3669),
+///     (Lab::new(50.0000, 2.4900, -0.0010), Lab::new(50.0000, -2.4900, 0.0009), 7.1792),
+///     (Lab::new(50.0000, 2.4900, -0.0010), Lab::new(50.0000, -2.4900, 0.0010), 7.1792),
+///     (Lab::new(50.0000, 2.4900, -0.0010), Lab::new(50.0000, -2.4900, 0.0011), 7.2195),
+///     (Lab::new(50.0000, 2.4900, -0.0010), Lab::new(50.0000, -2.4900, 0.0012), 7.2195),
+///     (Lab::new(50.0000, -0.0010, 2.4900), Lab::new(50.0000, 0.0009, -2.4900), 4.8045),
+///     (Lab::new(50.0000, -0.0010, 2.4900), Lab::new(50.0000, 0.0010, -2.4900), 4.8045),
+///     (Lab::new(50.0000, -0.0010, 2.4900), Lab::new(50.0000, 0.0011, -2.4900), 4.7461),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(50.0000, 0.0000, -2.5000), 4.3065),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(73.0000, 25.0000, -18.0000), 27.1492),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(61.0000, -5.0000, 29.0000), 22.8977),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(56.0000, -27.0000, -3.0000), 31.9030),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(58.0000, 24.0000, 15.0000), 19.4535),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(50.0000, 3.1736, 0.5854), 1.0000),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(50.0000, 3.2972, 0.0000), 1.0000),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(50.0000, 1.8634, 0.5757), 1.0000),
+///     (Lab::new(50.0000, 2.5000, 0.0000), Lab::new(50.0000, 3.2592, 0.3350), 1.0000),
+///     (Lab::new(60.2574, -34.0099, 36.2677), Lab::new(60.4626, -34.1751, 39.4387), 1.2644),
+///     (Lab::new(63.0109, -31.0961, -5.8663), Lab::new(62.8187, -29.7946, -4.0864), 1.2630),
+///     (Lab::new(61.2901, 3.7196, -5.3901), Lab::new(61.4292, 2.2480, -4.9620), 1.8731),
+///     (Lab::new(35.0831, -44.1164, 3.7933), Lab::new(35.0232, -40.0716, 1.5901), 1.8645),
+///     (Lab::new(22.7233, 20.0904, -46.6940), Lab::new(23.0331, 14.9730, -42.5619), 2.0373),
+///     (Lab::new(36.4612, 47.8580, 18.3852), Lab::new(36.2715, 50.5065, 21.2231), 1.4146),
+///     (Lab::new(90.8027, -2.0831, 1.4410), Lab::new(91.1528, -1.6435, 0.0447), 1.4441),
+///     (Lab::new(90.9257, -0.5406, -0.9208), Lab::new(88.6381, -0.8985, -0.7239), 1.5381),
+///     (Lab::new(6.7747, -0.2908, -2.4247), Lab::new(5.8714, -0.0985, -2.2286), 0.6377),
+///     (Lab::new(2.0776, 0.0795, -1.1350), Lab::new(0.9033, -0.0636, -0.5514), 0.9082),
+/// ];
+///
+/// for (reference, sample, expected) in pairs {
+///     assert!((delta_e::ciede2000(reference, sample) - expected).abs() < 1e-4);
+///     assert!((delta_e::ciede2000(sample, reference) - expected).abs() < 1e-4);
+/// }
+/// ```
+#[must_use]
+pub fn ciede2000(reference: Lab, sample: Lab) -> f32 {
+    /// `25^7`, which comes up in the chroma corrections.
+    const TWENTY_FIVE_TO_THE_SEVENTH: f32 = 6_103_515_625.0;
+    let seventh = |x: f32| {
+        let cubed = x * x * x;
+        cubed * cubed * x
+    };
+    let chroma_weight = |c: f32| sqrt(seventh(c) / (seventh(c) + TWENTY_FIVE_TO_THE_SEVENTH));
+
+    let c1 = sqrt(reference.a * reference.a + reference.b * reference.b);
+    let c2 = sqrt(sample.a * sample.a + sample.b * sample.b);
+    let g = 0.5 * (1.0 - chroma_weight(f32::midpoint(c1, c2)));
+
+    let a1 = (1.0 + g) * reference.a;
+    let a2 = (1.0 + g) * sample.a;
+    let c1 = sqrt(a1 * a1 + reference.b * reference.b);
+    let c2 = sqrt(a2 * a2 + sample.b * sample.b);
+    let hue = |b: f32, a: f32, c: f32| {
+        if c == 0.0 {
+            0.0
+        } else {
+            normalize_hue(atan2(b, a).to_degrees())
+        }
+    };
+    let h1 = hue(reference.b, a1, c1);
+    let h2 = hue(sample.b, a2, c2);
+
+    let dl = sample.l - reference.l;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * sqrt(c1 * c2) * sin((dh / 2.0).to_radians());
+
+    let l = f32::midpoint(reference.l, sample.l);
+    let c = f32::midpoint(c1, c2);
+    let h = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        f32::midpoint(h1, h2)
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * cos((h - 30.0).to_radians())
+        + 0.24 * cos((2.0 * h).to_radians())
+        + 0.32 * cos((3.0 * h + 6.0).to_radians())
+        - 0.20 * cos((4.0 * h - 63.0).to_radians());
+    let rotation = 30.0 * exp(-((h - 275.0) / 25.0) * ((h - 275.0) / 25.0));
+    let rt = -sin((2.0 * rotation).to_radians()) * 2.0 * chroma_weight(c);
+
+    let l_50 = (l - 50.0) * (l - 50.0);
+    let sl = 1.0 + 0.015 * l_50 / sqrt(20.0 + l_50);
+    let sc = 1.0 + 0.045 * c;
+    let sh = 1.0 + 0.015 * c * t;
+
+    let (dl, dc, dh) = (dl / sl, dc / sc, dh / sh);
+    sqrt(dl * dl + dc * dc + dh * dh + rt * dc * dh)
+}
+
+/// Returns the ΔEOK color difference between two [`Oklab`] colors, i.e., the
+/// Euclidean distance between them.
+///
+/// On this scale, a difference of about `0.02` is the smallest one that's
+/// noticeable.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::delta_e;
+/// use hex_color::Oklab;
+///
+/// let distance = delta_e::oklab(Oklab::new(0.5, 0.0, 0.0), Oklab::new(0.5, 0.03, 0.04));
+/// assert!((distance - 0.05).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn oklab(reference: Oklab, sample: Oklab) -> f32 {
+    let (dl, da, db) = (
+        reference.l - sample.l,
+        reference.a - sample.a,
+        reference.b - sample.b,
+    );
+    sqrt(dl * dl + da * da + db * db)
+}
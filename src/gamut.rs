@@ -0,0 +1,364 @@
+use crate::convert::{self, Matrix};
+use crate::{HexColor, LinearRgba};
+
+/// How a color outside of the sRGB gamut is brought into it when it's
+/// converted to a [`HexColor`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub enum GamutMapping {
+    /// Each channel is clamped to its range independently. This is fast and
+    /// keeps in-gamut channels as they are, but can noticeably shift the hue
+    /// of very saturated colors.
+    Clip,
+    /// The chroma is reduced in [`Oklch`](crate::Oklch), keeping the
+    /// lightness and hue, like [`HexColor::from_oklch`] does.
+    #[default]
+    ReduceChroma,
+}
+
+impl GamutMapping {
+    /// Maps a linear-light sRGB color into the sRGB gamut and gamma-encodes
+    /// it.
+    fn apply(self, rgb: [f32; 3]) -> [f32; 3] {
+        match self {
+            GamutMapping::Clip => convert::clip_linear_srgb(rgb),
+            // Colors that only round into the gamut are clipped, so that
+            // they're treated like `in_srgb_gamut` says they should be.
+            GamutMapping::ReduceChroma if in_srgb_gamut(rgb) => convert::clip_linear_srgb(rgb),
+            GamutMapping::ReduceChroma => convert::gamut_map_linear_srgb(rgb),
+        }
+    }
+}
+
+/// A color in the [Display P3] color space, with an alpha component carried
+/// along unchanged from, and back to, [`HexColor`].
+///
+/// Display P3 shares its white point and transfer function with sRGB, but its
+/// red and green primaries are a lot more saturated, which is why it's what
+/// most recent displays, and so most designers, use. This is the color space
+/// of CSS `color(display-p3 ...)`.
+///
+/// The components are gamma-encoded and are in the range `0.0..=1.0` for
+/// colors in the Display P3 gamut.
+///
+/// [Display P3]: https://www.w3.org/TR/css-color-4/#valdef-color-display-p3
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{DisplayP3, GamutMapping, HexColor};
+///
+/// let p3 = DisplayP3::new(0.5, 0.8, 0.3);
+/// assert!(p3.in_srgb_gamut());
+/// assert_eq!(HexColor::from(p3), HexColor::rgb(100, 206, 47));
+///
+/// let red = DisplayP3::new(1.0, 0.0, 0.0);
+/// assert!(!red.in_srgb_gamut());
+/// assert_eq!(HexColor::from_display_p3(red, GamutMapping::Clip), HexColor::RED);
+/// assert_eq!(
+///     HexColor::from_display_p3(red, GamutMapping::ReduceChroma),
+///     HexColor::rgb(255, 11, 12),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct DisplayP3 {
+    /// The red component of the color.
+    pub r: f32,
+    /// The green component of the color.
+    pub g: f32,
+    /// The blue component of the color.
+    pub b: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl DisplayP3 {
+    /// Constructs a new, opaque `DisplayP3` color from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32) -> DisplayP3 {
+        DisplayP3 { r, g, b, a: 255 }
+    }
+
+    /// Constructs a new `DisplayP3` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> DisplayP3 {
+        DisplayP3 { a, ..self }
+    }
+
+    /// Returns whether the color is inside of the sRGB gamut, i.e., whether
+    /// it can be converted to a [`HexColor`] without any gamut mapping.
+    ///
+    /// Being off by less than half of an 8-bit step, which rounding makes up
+    /// for, still counts as being inside of the gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{DisplayP3, HexColor};
+    ///
+    /// assert!(HexColor::RED.to_display_p3().in_srgb_gamut());
+    /// assert!(!DisplayP3::new(0.0, 1.0, 0.0).in_srgb_gamut());
+    /// ```
+    ///
+    /// Colors inside of the gamut are left alone by either [`GamutMapping`]:
+    ///
+    /// ```
+    /// use hex_color::{DisplayP3, GamutMapping, HexColor};
+    ///
+    /// let steps = (0..=20).map(|n| n as f32 / 20.0);
+    /// for r in steps.clone() {
+    ///     for g in steps.clone() {
+    ///         for b in steps.clone() {
+    ///             let p3 = DisplayP3::new(r, g, b);
+    ///             if p3.in_srgb_gamut() {
+    ///                 assert_eq!(
+    ///                     HexColor::from_display_p3(p3, GamutMapping::ReduceChroma),
+    ///                     HexColor::from_display_p3(p3, GamutMapping::Clip),
+    ///                 );
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn in_srgb_gamut(self) -> bool {
+        in_srgb_gamut(self.to_linear_srgb())
+    }
+
+    fn to_linear_srgb(self) -> [f32; 3] {
+        let rgb = [self.r, self.g, self.b].map(convert::srgb_to_linear);
+        convert::multiply(&convert::LINEAR_DISPLAY_P3_TO_LINEAR_SRGB, rgb)
+    }
+}
+
+/// A color in the [Rec. 2020] color space, with an alpha component carried
+/// along unchanged from, and back to, [`HexColor`].
+///
+/// Rec. 2020 is the color space of ultra-high-definition video and HDR, and
+/// its gamut is even wider than [`DisplayP3`]'s. This is the color space of
+/// CSS `color(rec2020 ...)`.
+///
+/// The components are gamma-encoded and are in the range `0.0..=1.0` for
+/// colors in the Rec. 2020 gamut.
+///
+/// [Rec. 2020]: https://www.w3.org/TR/css-color-4/#valdef-color-rec2020
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{GamutMapping, HexColor, Rec2020};
+///
+/// let green = Rec2020::new(0.0, 1.0, 0.0);
+/// assert!(!green.in_srgb_gamut());
+/// assert_eq!(HexColor::from_rec2020(green, GamutMapping::Clip), HexColor::GREEN);
+/// assert_eq!(
+///     HexColor::from_rec2020(green, GamutMapping::ReduceChroma),
+///     HexColor::rgb(0, 242, 114),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Rec2020 {
+    /// The red component of the color.
+    pub r: f32,
+    /// The green component of the color.
+    pub g: f32,
+    /// The blue component of the color.
+    pub b: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl Rec2020 {
+    /// Constructs a new, opaque `Rec2020` color from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32) -> Rec2020 {
+        Rec2020 { r, g, b, a: 255 }
+    }
+
+    /// Constructs a new `Rec2020` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> Rec2020 {
+        Rec2020 { a, ..self }
+    }
+
+    /// Returns whether the color is inside of the sRGB gamut, i.e., whether
+    /// it can be converted to a [`HexColor`] without any gamut mapping.
+    ///
+    /// Being off by less than half of an 8-bit step, which rounding makes up
+    /// for, still counts as being inside of the gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Rec2020};
+    ///
+    /// assert!(HexColor::GREEN.to_rec2020().in_srgb_gamut());
+    /// assert!(!Rec2020::new(1.0, 0.0, 0.0).in_srgb_gamut());
+    /// ```
+    #[must_use]
+    pub fn in_srgb_gamut(self) -> bool {
+        in_srgb_gamut(self.to_linear_srgb())
+    }
+
+    fn to_linear_srgb(self) -> [f32; 3] {
+        let rgb = [self.r, self.g, self.b].map(convert::rec2020_to_linear);
+        convert::multiply(&convert::LINEAR_REC2020_TO_LINEAR_SRGB, rgb)
+    }
+}
+
+/// Returns whether a linear-light sRGB color rounds to an 8-bit color without
+/// clamping, which is also when [`GamutMapping::ReduceChroma`] leaves it alone.
+fn in_srgb_gamut(rgb: [f32; 3]) -> bool {
+    const TOLERANCE: f32 = 0.5 / 255.0;
+    rgb.map(convert::linear_to_srgb)
+        .iter()
+        .all(|c| (-TOLERANCE..=1.0 + TOLERANCE).contains(c))
+}
+
+/// Converts a `HexColor` to a wider gamut with the matrix from linear-light
+/// sRGB and the transfer function of the color space.
+fn from_srgb(color: HexColor, matrix: &Matrix, encode: fn(f32) -> f32) -> [f32; 3] {
+    let LinearRgba { r, g, b, .. } = color.to_linear();
+    convert::multiply(matrix, [r, g, b]).map(encode)
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Converts the color to [`DisplayP3`], preserving the alpha component.
+    ///
+    /// Every sRGB color is inside of the Display P3 gamut, so this never
+    /// needs any gamut mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let p3 = HexColor::RED.to_display_p3();
+    /// assert!((p3.r - 0.9175).abs() < 1e-4);
+    /// assert!((p3.g - 0.2003).abs() < 1e-4);
+    /// assert!((p3.b - 0.1386).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn to_display_p3(self) -> DisplayP3 {
+        let [r, g, b] = from_srgb(
+            self,
+            &convert::LINEAR_SRGB_TO_LINEAR_DISPLAY_P3,
+            convert::linear_to_srgb,
+        );
+        DisplayP3 { r, g, b, a: self.a }
+    }
+
+    /// Converts a [`DisplayP3`] color to the nearest `HexColor`, preserving
+    /// the alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are brought into it with `mapping`;
+    /// see [`DisplayP3::in_srgb_gamut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{GamutMapping, HexColor};
+    ///
+    /// for n in (0..=0xFF_FFFF).step_by(997) {
+    ///     let color = HexColor::from_u24(n);
+    ///     let p3 = color.to_display_p3();
+    ///     assert_eq!(HexColor::from_display_p3(p3, GamutMapping::Clip), color);
+    ///     assert_eq!(HexColor::from_display_p3(p3, GamutMapping::ReduceChroma), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_display_p3(p3: DisplayP3, mapping: GamutMapping) -> HexColor {
+        convert::srgb_to_hex_color(mapping.apply(p3.to_linear_srgb()), p3.a)
+    }
+
+    /// Converts the color to [`Rec2020`], preserving the alpha component.
+    ///
+    /// Every sRGB color is inside of the Rec. 2020 gamut, so this never needs
+    /// any gamut mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let rec2020 = HexColor::RED.to_rec2020();
+    /// assert!((rec2020.r - 0.7920).abs() < 1e-4);
+    /// assert!((rec2020.g - 0.2310).abs() < 1e-4);
+    /// assert!((rec2020.b - 0.0738).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn to_rec2020(self) -> Rec2020 {
+        let [r, g, b] = from_srgb(
+            self,
+            &convert::LINEAR_SRGB_TO_LINEAR_REC2020,
+            convert::linear_to_rec2020,
+        );
+        Rec2020 { r, g, b, a: self.a }
+    }
+
+    /// Converts a [`Rec2020`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are brought into it with `mapping`;
+    /// see [`Rec2020::in_srgb_gamut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{GamutMapping, HexColor};
+    ///
+    /// for n in (0..=0xFF_FFFF).step_by(997) {
+    ///     let color = HexColor::from_u24(n);
+    ///     let rec2020 = color.to_rec2020();
+    ///     assert_eq!(HexColor::from_rec2020(rec2020, GamutMapping::Clip), color);
+    ///     assert_eq!(HexColor::from_rec2020(rec2020, GamutMapping::ReduceChroma), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_rec2020(rec2020: Rec2020, mapping: GamutMapping) -> HexColor {
+        convert::srgb_to_hex_color(mapping.apply(rec2020.to_linear_srgb()), rec2020.a)
+    }
+}
+
+impl From<HexColor> for DisplayP3 {
+    /// Constructs a new `DisplayP3` from a `HexColor` via
+    /// [`HexColor::to_display_p3`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_display_p3()
+    }
+}
+
+impl From<DisplayP3> for HexColor {
+    /// Constructs a new `HexColor` from a `DisplayP3` via
+    /// [`HexColor::from_display_p3`] with the default [`GamutMapping`].
+    #[inline]
+    fn from(p3: DisplayP3) -> Self {
+        HexColor::from_display_p3(p3, GamutMapping::default())
+    }
+}
+
+impl From<HexColor> for Rec2020 {
+    /// Constructs a new `Rec2020` from a `HexColor` via
+    /// [`HexColor::to_rec2020`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_rec2020()
+    }
+}
+
+impl From<Rec2020> for HexColor {
+    /// Constructs a new `HexColor` from a `Rec2020` via
+    /// [`HexColor::from_rec2020`] with the default [`GamutMapping`].
+    #[inline]
+    fn from(rec2020: Rec2020) -> Self {
+        HexColor::from_rec2020(rec2020, GamutMapping::default())
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{normalize_hue, round_to_u8, HexColor};
+
+/// A color in the HSL (hue, saturation, lightness) model, with an alpha
+/// component carried along unchanged from, and back to, [`HexColor`].
+///
+/// HSL is a cylindrical rearrangement of sRGB, so every `HexColor` has an
+/// exact `Hsl` equivalent, and [`HexColor::from_hsl`] undoes
+/// [`HexColor::to_hsl`] for every color.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Hsl};
+///
+/// let rebecca_purple = HexColor::rgb(102, 51, 153);
+///
+/// let hsl = rebecca_purple.to_hsl();
+/// assert_eq!(hsl.h, 270.0);
+/// assert_eq!(hsl.s, 0.5);
+/// assert_eq!(hsl.l, 0.4);
+///
+/// assert_eq!(HexColor::from_hsl(hsl), rebecca_purple);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Hsl {
+    /// The hue of the color in degrees, in the range `0.0..360.0`.
+    ///
+    /// Achromatic colors, i.e., grays, don't have a hue, and use `0.0`.
+    pub h: f32,
+    /// The saturation of the color, in the range `0.0..=1.0`.
+    pub s: f32,
+    /// The lightness of the color, in the range `0.0..=1.0`.
+    pub l: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl Hsl {
+    /// Constructs a new, opaque `Hsl` color from its hue in degrees, and its
+    /// saturation and lightness in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsl};
+    ///
+    /// let lime = Hsl::new(120.0, 1.0, 0.5);
+    /// assert_eq!(HexColor::from_hsl(lime), HexColor::GREEN);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(h: f32, s: f32, l: f32) -> Hsl {
+        Hsl { h, s, l, a: 255 }
+    }
+
+    /// Constructs a new `Hsl` color with the given alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsl};
+    ///
+    /// let translucent_lime = Hsl::new(120.0, 1.0, 0.5).with_a(128);
+    /// assert_eq!(
+    ///     HexColor::from_hsl(translucent_lime),
+    ///     HexColor::rgba(0, 255, 0, 128),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> Hsl {
+        Hsl { a, ..self }
+    }
+
+    /// Converts the color to gamma-encoded sRGB, without rounding.
+    pub(crate) fn to_srgb(self) -> [f32; 3] {
+        let hue = normalize_hue(self.h);
+        let saturation = self.s.clamp(0.0, 1.0);
+        let lightness = self.l.clamp(0.0, 1.0);
+
+        let a = saturation * lightness.min(1.0 - lightness);
+        let f = |n: f32| {
+            let k = (n + hue / 30.0) % 12.0;
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        [f(0.0), f(8.0), f(4.0)]
+    }
+}
+
+impl HexColor {
+    /// Converts the color to [`Hsl`], preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsl};
+    ///
+    /// assert_eq!(HexColor::RED.to_hsl(), Hsl::new(0.0, 1.0, 0.5));
+    /// assert_eq!(HexColor::GRAY.to_hsl().s, 0.0);
+    /// assert_eq!(HexColor::CLEAR.to_hsl(), Hsl::new(0.0, 0.0, 0.0).with_a(0));
+    /// ```
+    #[must_use]
+    pub fn to_hsl(self) -> Hsl {
+        let (h, max, min) = hue(self);
+        let l = f32::from(max + min) / 510.0;
+        let s = if max == min {
+            0.0
+        } else {
+            f32::from(max - min) / f32::from(255 - (max + min - 255).abs())
+        };
+
+        Hsl { h, s, l, a: self.a }
+    }
+
+    /// Converts an [`Hsl`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// The hue wraps around, so `-120.0` and `240.0` are the same hue, and the
+    /// saturation and lightness are clamped to the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsl};
+    ///
+    /// assert_eq!(HexColor::from_hsl(Hsl::new(240.0, 1.0, 0.5)), HexColor::BLUE);
+    /// assert_eq!(HexColor::from_hsl(Hsl::new(-120.0, 1.0, 0.5)), HexColor::BLUE);
+    /// assert_eq!(HexColor::from_hsl(Hsl::new(0.0, 0.0, 2.0)), HexColor::WHITE);
+    /// ```
+    ///
+    /// Converting to [`Hsl`] and back is lossless for every color:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// for n in 0..=0xFF_FFFF {
+    ///     let color = HexColor::from_u24(n);
+    ///     assert_eq!(HexColor::from_hsl(color.to_hsl()), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_hsl(hsl: Hsl) -> HexColor {
+        let [r, g, b] = hsl.to_srgb().map(|c| round_to_u8(c * 255.0));
+        HexColor::rgba(r, g, b, hsl.a)
+    }
+}
+
+impl From<HexColor> for Hsl {
+    /// Constructs a new `Hsl` from a `HexColor` via [`HexColor::to_hsl`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_hsl()
+    }
+}
+
+impl From<Hsl> for HexColor {
+    /// Constructs a new `HexColor` from an `Hsl` via [`HexColor::from_hsl`].
+    #[inline]
+    fn from(hsl: Hsl) -> Self {
+        HexColor::from_hsl(hsl)
+    }
+}
+
+/// Returns the hue of the color in degrees, along with its largest and
+/// smallest RGB components.
+///
+/// Working with the integer components for as long as possible keeps the
+/// other cylindrical coordinates of simple colors exact.
+pub(crate) fn hue(color: HexColor) -> (f32, i16, i16) {
+    let [r, g, b] = [color.r, color.g, color.b].map(i16::from);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    if max == min {
+        return (0.0, max, min);
+    }
+
+    let delta = f32::from(max - min);
+    let hue = if max == r {
+        f32::from(g - b) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        f32::from(b - r) / delta + 2.0
+    } else {
+        f32::from(r - g) / delta + 4.0
+    };
+    (hue * 60.0, max, min)
+}
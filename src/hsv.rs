@@ -0,0 +1,162 @@
+use crate::{hsl, normalize_hue, round_to_u8, HexColor};
+
+/// A color in the HSV (hue, saturation, value) model, also known as HSB
+/// (hue, saturation, brightness), with an alpha component carried along
+/// unchanged from, and back to, [`HexColor`].
+///
+/// This is the model most color pickers work in. Like [`Hsl`](crate::Hsl),
+/// it's a cylindrical rearrangement of sRGB, so [`HexColor::from_hsv`] undoes
+/// [`HexColor::to_hsv`] for every color.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Hsv};
+///
+/// let orange = HexColor::rgb(255, 153, 0);
+///
+/// let hsv = orange.to_hsv();
+/// assert_eq!(hsv.h, 36.0);
+/// assert_eq!(hsv.s, 1.0);
+/// assert_eq!(hsv.v, 1.0);
+///
+/// assert_eq!(HexColor::from_hsv(hsv), orange);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Hsv {
+    /// The hue of the color in degrees, in the range `0.0..360.0`.
+    ///
+    /// Achromatic colors, i.e., grays, don't have a hue, and use `0.0`.
+    pub h: f32,
+    /// The saturation of the color, in the range `0.0..=1.0`.
+    pub s: f32,
+    /// The value, or brightness, of the color, in the range `0.0..=1.0`.
+    pub v: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl Hsv {
+    /// Constructs a new, opaque `Hsv` color from its hue in degrees, and its
+    /// saturation and value in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsv};
+    ///
+    /// let yellow = Hsv::new(60.0, 1.0, 1.0);
+    /// assert_eq!(HexColor::from_hsv(yellow), HexColor::YELLOW);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(h: f32, s: f32, v: f32) -> Hsv {
+        Hsv { h, s, v, a: 255 }
+    }
+
+    /// Constructs a new `Hsv` color with the given alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsv};
+    ///
+    /// let translucent_yellow = Hsv::new(60.0, 1.0, 1.0).with_a(128);
+    /// assert_eq!(
+    ///     HexColor::from_hsv(translucent_yellow),
+    ///     HexColor::rgba(255, 255, 0, 128),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> Hsv {
+        Hsv { a, ..self }
+    }
+
+    /// Converts the color to gamma-encoded sRGB, without rounding.
+    pub(crate) fn to_srgb(self) -> [f32; 3] {
+        let hue = normalize_hue(self.h);
+        let saturation = self.s.clamp(0.0, 1.0);
+        let value = self.v.clamp(0.0, 1.0);
+
+        let f = |n: f32| {
+            let k = (n + hue / 60.0) % 6.0;
+            value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        [f(5.0), f(3.0), f(1.0)]
+    }
+}
+
+impl HexColor {
+    /// Converts the color to [`Hsv`], preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsv};
+    ///
+    /// assert_eq!(HexColor::BLUE.to_hsv(), Hsv::new(240.0, 1.0, 1.0));
+    /// assert_eq!(HexColor::GRAY.to_hsv().s, 0.0);
+    /// assert_eq!(HexColor::CLEAR.to_hsv(), Hsv::new(0.0, 0.0, 0.0).with_a(0));
+    /// ```
+    #[must_use]
+    pub fn to_hsv(self) -> Hsv {
+        let (h, max, min) = hsl::hue(self);
+        let v = f32::from(max) / 255.0;
+        let s = if max == 0 {
+            0.0
+        } else {
+            f32::from(max - min) / f32::from(max)
+        };
+
+        Hsv { h, s, v, a: self.a }
+    }
+
+    /// Converts an [`Hsv`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// The hue wraps around, so `-120.0` and `240.0` are the same hue, and the
+    /// saturation and value are clamped to the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hsv};
+    ///
+    /// assert_eq!(HexColor::from_hsv(Hsv::new(300.0, 1.0, 1.0)), HexColor::MAGENTA);
+    /// assert_eq!(HexColor::from_hsv(Hsv::new(-60.0, 1.0, 1.0)), HexColor::MAGENTA);
+    /// assert_eq!(HexColor::from_hsv(Hsv::new(0.0, 0.0, 0.5)), HexColor::GRAY);
+    /// ```
+    ///
+    /// Converting to [`Hsv`] and back is lossless for every color:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// for n in 0..=0xFF_FFFF {
+    ///     let color = HexColor::from_u24(n);
+    ///     assert_eq!(HexColor::from_hsv(color.to_hsv()), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_hsv(hsv: Hsv) -> HexColor {
+        let [r, g, b] = hsv.to_srgb().map(|c| round_to_u8(c * 255.0));
+        HexColor::rgba(r, g, b, hsv.a)
+    }
+}
+
+impl From<HexColor> for Hsv {
+    /// Constructs a new `Hsv` from a `HexColor` via [`HexColor::to_hsv`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_hsv()
+    }
+}
+
+impl From<Hsv> for HexColor {
+    /// Constructs a new `HexColor` from an `Hsv` via [`HexColor::from_hsv`].
+    #[inline]
+    fn from(hsv: Hsv) -> Self {
+        HexColor::from_hsv(hsv)
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{hsl, HexColor, Hsv};
+
+/// A color in the HWB (hue, whiteness, blackness) model, with an alpha
+/// component carried along unchanged from, and back to, [`HexColor`].
+///
+/// HWB describes a color as a pure hue, tinted with white and shaded with
+/// black, which is how CSS `hwb()` works. It's a simple transformation of
+/// [`Hsv`], which the conversions go through, and [`HexColor::from_hwb`]
+/// undoes [`HexColor::to_hwb`] for every color.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Hwb};
+///
+/// let pink = HexColor::rgb(255, 128, 191);
+///
+/// let hwb = pink.to_hwb();
+/// assert_eq!(hwb.w, 128.0 / 255.0);
+/// assert_eq!(hwb.b, 0.0);
+///
+/// assert_eq!(HexColor::from_hwb(hwb), pink);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Hwb {
+    /// The hue of the color in degrees, in the range `0.0..360.0`.
+    ///
+    /// Achromatic colors, i.e., grays, don't have a hue, and use `0.0`.
+    pub h: f32,
+    /// The whiteness of the color, in the range `0.0..=1.0`.
+    pub w: f32,
+    /// The blackness of the color, in the range `0.0..=1.0`.
+    pub b: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl Hwb {
+    /// Constructs a new, opaque `Hwb` color from its hue in degrees, and its
+    /// whiteness and blackness in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hwb};
+    ///
+    /// let cyan = Hwb::new(180.0, 0.0, 0.0);
+    /// assert_eq!(HexColor::from_hwb(cyan), HexColor::CYAN);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(h: f32, w: f32, b: f32) -> Hwb {
+        Hwb { h, w, b, a: 255 }
+    }
+
+    /// Constructs a new `Hwb` color with the given alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hwb};
+    ///
+    /// let translucent_cyan = Hwb::new(180.0, 0.0, 0.0).with_a(128);
+    /// assert_eq!(
+    ///     HexColor::from_hwb(translucent_cyan),
+    ///     HexColor::rgba(0, 255, 255, 128),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> Hwb {
+        Hwb { a, ..self }
+    }
+
+    /// Clamps the whiteness and blackness to the range `0.0..=1.0`, and, if
+    /// they add up to more than `1.0`, scales them down proportionally so
+    /// that they add up to exactly `1.0`.
+    ///
+    /// Every color with a whiteness and blackness adding up to `1.0` or more
+    /// is a gray, so this doesn't change which color is described.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::Hwb;
+    ///
+    /// let gray = Hwb::new(90.0, 0.6, 0.6).normalize();
+    /// assert_eq!((gray.w, gray.b), (0.5, 0.5));
+    ///
+    /// let pale = Hwb::new(90.0, 0.6, 0.2);
+    /// assert_eq!(pale.normalize(), pale);
+    /// ```
+    #[must_use]
+    pub fn normalize(self) -> Hwb {
+        let w = self.w.clamp(0.0, 1.0);
+        let b = self.b.clamp(0.0, 1.0);
+        let sum = w + b;
+        if sum > 1.0 {
+            Hwb {
+                w: w / sum,
+                b: b / sum,
+                ..self
+            }
+        } else {
+            Hwb { w, b, ..self }
+        }
+    }
+}
+
+impl HexColor {
+    /// Converts the color to [`Hwb`], preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hwb};
+    ///
+    /// assert_eq!(HexColor::RED.to_hwb(), Hwb::new(0.0, 0.0, 0.0));
+    /// assert_eq!(HexColor::WHITE.to_hwb(), Hwb::new(0.0, 1.0, 0.0));
+    /// assert_eq!(HexColor::CLEAR.to_hwb(), Hwb::new(0.0, 0.0, 1.0).with_a(0));
+    /// ```
+    #[must_use]
+    pub fn to_hwb(self) -> Hwb {
+        let (h, max, min) = hsl::hue(self);
+        Hwb {
+            h,
+            w: f32::from(min) / 255.0,
+            b: f32::from(255 - max) / 255.0,
+            a: self.a,
+        }
+    }
+
+    /// Converts an [`Hwb`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// The color is [normalized](Hwb::normalize) first, and the hue wraps
+    /// around, so `-120.0` and `240.0` are the same hue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Hwb};
+    ///
+    /// assert_eq!(HexColor::from_hwb(Hwb::new(120.0, 0.0, 0.5)), HexColor::rgb(0, 128, 0));
+    /// assert_eq!(HexColor::from_hwb(Hwb::new(-240.0, 0.0, 0.5)), HexColor::rgb(0, 128, 0));
+    /// assert_eq!(HexColor::from_hwb(Hwb::new(0.0, 2.0, 2.0)), HexColor::GRAY);
+    /// ```
+    ///
+    /// Converting to [`Hwb`] and back is lossless for every color:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// for n in 0..=0xFF_FFFF {
+    ///     let color = HexColor::from_u24(n);
+    ///     assert_eq!(HexColor::from_hwb(color.to_hwb()), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_hwb(hwb: Hwb) -> HexColor {
+        HexColor::from_hsv(hwb.into())
+    }
+}
+
+impl From<HexColor> for Hwb {
+    /// Constructs a new `Hwb` from a `HexColor` via [`HexColor::to_hwb`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_hwb()
+    }
+}
+
+impl From<Hwb> for HexColor {
+    /// Constructs a new `HexColor` from an `Hwb` via [`HexColor::from_hwb`].
+    #[inline]
+    fn from(hwb: Hwb) -> Self {
+        HexColor::from_hwb(hwb)
+    }
+}
+
+impl From<Hsv> for Hwb {
+    /// Converts an `Hsv` color to `Hwb`, preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Hsv, Hwb};
+    ///
+    /// let hwb = Hwb::from(Hsv::new(30.0, 0.5, 0.8));
+    /// assert_eq!(hwb.h, 30.0);
+    /// assert!((hwb.w - 0.4).abs() < 1e-6);
+    /// assert!((hwb.b - 0.2).abs() < 1e-6);
+    /// ```
+    fn from(hsv: Hsv) -> Self {
+        Hwb {
+            h: hsv.h,
+            w: (1.0 - hsv.s) * hsv.v,
+            b: 1.0 - hsv.v,
+            a: hsv.a,
+        }
+    }
+}
+
+impl From<Hwb> for Hsv {
+    /// Converts an `Hwb` color to `Hsv`, preserving the alpha component. The
+    /// `Hwb` color is [normalized](Hwb::normalize) first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Hsv, Hwb};
+    ///
+    /// let hsv = Hsv::from(Hwb::new(30.0, 0.4, 0.2));
+    /// assert_eq!(hsv.h, 30.0);
+    /// assert!((hsv.s - 0.5).abs() < 1e-6);
+    /// assert!((hsv.v - 0.8).abs() < 1e-6);
+    /// ```
+    fn from(hwb: Hwb) -> Self {
+        let Hwb { h, w, b, a } = hwb.normalize();
+        let v = 1.0 - b;
+        let s = if v > 0.0 { 1.0 - w / v } else { 0.0 };
+        Hsv { h, s, v, a }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::math::{atan2, cbrt, cos, sin, sqrt};
+use crate::{normalize_hue, HexColor, WhitePoint, Xyz};
+
+/// `ε` of the CIE standard, as an exact fraction.
+const EPSILON: f32 = 216.0 / 24389.0;
+/// `κ` of the CIE standard, as an exact fraction.
+const KAPPA: f32 = 24389.0 / 27.0;
+
+/// A color in the CIE 1976 L\*a\*b\* color space, with an alpha component
+/// carried along unchanged from, and back to, [`HexColor`].
+///
+/// The coordinates are relative to a [`WhitePoint`], which isn't stored in
+/// the color. CSS `lab()`, like most other uses of L\*a\*b\*, uses
+/// [`WhitePoint::D50`].
+///
+/// Since `a` is one of the coordinates, the alpha component is called
+/// `alpha` instead.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Lab, WhitePoint};
+///
+/// let lab = HexColor::WHITE.to_lab(WhitePoint::D50);
+/// assert!((lab.l - 100.0).abs() < 1e-3);
+/// assert!(lab.a.abs() < 1e-3 && lab.b.abs() < 1e-3);
+///
+/// let purple = Lab::new(32.4, 38.4, -47.7);
+/// assert_eq!(HexColor::from_lab(purple, WhitePoint::D50), HexColor::rgb(102, 51, 153));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Lab {
+    /// The lightness of the color, in the range `0.0..=100.0`.
+    pub l: f32,
+    /// The position of the color between green (negative) and red
+    /// (positive), which, in practice, is in the range `-125.0..=125.0`.
+    pub a: f32,
+    /// The position of the color between blue (negative) and yellow
+    /// (positive), which, in practice, is in the range `-125.0..=125.0`.
+    pub b: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub alpha: u8,
+}
+
+impl Lab {
+    /// Constructs a new, opaque `Lab` color from its coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn new(l: f32, a: f32, b: f32) -> Lab {
+        Lab {
+            l,
+            a,
+            b,
+            alpha: 255,
+        }
+    }
+
+    /// Constructs a new `Lab` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_alpha(self, alpha: u8) -> Lab {
+        Lab { alpha, ..self }
+    }
+
+    /// Converts a CIE XYZ color relative to `white` to `Lab`, preserving the
+    /// alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Lab, WhitePoint};
+    ///
+    /// let white = Lab::from_xyz(WhitePoint::D65.xyz(), WhitePoint::D65);
+    /// assert_eq!(white, Lab::new(100.0, 0.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> Lab {
+        let reference = white.xyz();
+        let f = |t: f32| {
+            if t > EPSILON {
+                cbrt(t)
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let fx = f(xyz.x / reference.x);
+        let fy = f(xyz.y / reference.y);
+        let fz = f(xyz.z / reference.z);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+            alpha: xyz.a,
+        }
+    }
+
+    /// Converts the color to CIE XYZ relative to `white`, preserving the
+    /// alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{Lab, WhitePoint};
+    ///
+    /// let white = Lab::new(100.0, 0.0, 0.0).to_xyz(WhitePoint::D50);
+    /// assert_eq!(white, WhitePoint::D50.xyz());
+    /// ```
+    #[must_use]
+    pub fn to_xyz(self, white: WhitePoint) -> Xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+
+        let inverse = |f: f32| {
+            let cubed = f * f * f;
+            if cubed > EPSILON {
+                cubed
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.l / KAPPA
+        };
+
+        let reference = white.xyz();
+        Xyz {
+            x: inverse(fx) * reference.x,
+            y: y * reference.y,
+            z: inverse(fz) * reference.z,
+            a: self.alpha,
+        }
+    }
+}
+
+/// A color in the CIE LCh(ab) color space, the cylindrical form of [`Lab`],
+/// with an alpha component carried along unchanged from, and back to,
+/// [`HexColor`].
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Lch, WhitePoint};
+///
+/// let lch = HexColor::rgb(102, 51, 153).to_lch(WhitePoint::D50);
+/// assert!((lch.c - 61.2).abs() < 0.1);
+/// assert!((lch.h - 308.8).abs() < 0.1);
+///
+/// let teal = Lch::new(48.0, 30.0, 196.0);
+/// assert_eq!(HexColor::from_lch(teal, WhitePoint::D50), HexColor::rgb(23, 127, 127));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Lch {
+    /// The lightness of the color, in the range `0.0..=100.0`.
+    pub l: f32,
+    /// The chroma of the color, which is at least `0.0` and, in practice, at
+    /// most `150.0`.
+    pub c: f32,
+    /// The hue of the color in degrees, in the range `0.0..360.0`.
+    pub h: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub alpha: u8,
+}
+
+impl Lch {
+    /// Constructs a new, opaque `Lch` color from its coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn new(l: f32, c: f32, h: f32) -> Lch {
+        Lch {
+            l,
+            c,
+            h,
+            alpha: 255,
+        }
+    }
+
+    /// Constructs a new `Lch` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_alpha(self, alpha: u8) -> Lch {
+        Lch { alpha, ..self }
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Converts the color to [`Lab`] relative to `white`, preserving the
+    /// alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// let lab = HexColor::rgb(255, 0, 0).to_lab(WhitePoint::D50);
+    /// assert!((lab.l - 54.29).abs() < 0.01);
+    /// assert!((lab.a - 80.80).abs() < 0.01);
+    /// assert!((lab.b - 69.89).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn to_lab(self, white: WhitePoint) -> Lab {
+        Lab::from_xyz(self.to_xyz(white), white)
+    }
+
+    /// Converts a [`Lab`] color relative to `white` to the nearest
+    /// `HexColor`, preserving the alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are clipped; see
+    /// [`HexColor::from_xyz`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// for n in (0..=0xFF_FFFF).step_by(997) {
+    ///     let color = HexColor::from_u24(n);
+    ///     let lab = color.to_lab(WhitePoint::D50);
+    ///     assert_eq!(HexColor::from_lab(lab, WhitePoint::D50), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_lab(lab: Lab, white: WhitePoint) -> HexColor {
+        HexColor::from_xyz(lab.to_xyz(white), white)
+    }
+
+    /// Converts the color to [`Lch`] relative to `white`, preserving the
+    /// alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// let gray = HexColor::GRAY.to_lch(WhitePoint::D65);
+    /// assert!(gray.c < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn to_lch(self, white: WhitePoint) -> Lch {
+        self.to_lab(white).into()
+    }
+
+    /// Converts an [`Lch`] color relative to `white` to the nearest
+    /// `HexColor`, preserving the alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are clipped; see
+    /// [`HexColor::from_xyz`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Lch, WhitePoint};
+    ///
+    /// let gold = HexColor::rgb(255, 215, 0);
+    /// let lch = gold.to_lch(WhitePoint::D65);
+    /// assert_eq!(HexColor::from_lch(lch, WhitePoint::D65), gold);
+    ///
+    /// // Far too much chroma for sRGB:
+    /// let clipped = HexColor::from_lch(Lch::new(50.0, 150.0, 0.0), WhitePoint::D50);
+    /// assert_eq!(clipped, HexColor::rgb(255, 0, 128));
+    /// ```
+    #[must_use]
+    pub fn from_lch(lch: Lch, white: WhitePoint) -> HexColor {
+        HexColor::from_lab(lch.into(), white)
+    }
+}
+
+impl From<Lab> for Lch {
+    /// Converts a `Lab` color to its cylindrical form, preserving the alpha
+    /// component.
+    fn from(lab: Lab) -> Self {
+        Lch {
+            l: lab.l,
+            c: sqrt(lab.a * lab.a + lab.b * lab.b),
+            h: normalize_hue(atan2(lab.b, lab.a).to_degrees()),
+            alpha: lab.alpha,
+        }
+    }
+}
+
+impl From<Lch> for Lab {
+    /// Converts an `Lch` color to its rectangular form, preserving the alpha
+    /// component.
+    fn from(lch: Lch) -> Self {
+        let hue = lch.h.to_radians();
+        Lab {
+            l: lch.l,
+            a: lch.c * cos(hue),
+            b: lch.c * sin(hue),
+            alpha: lch.alpha,
+        }
+    }
+}
@@ -37,6 +37,15 @@
 //! # }
 //! ```
 //!
+//! Compile-time parsing:
+//!
+//! ```
+//! use hex_color::{hex, HexColor};
+//!
+//! const DODGER_BLUE: HexColor = hex!("#1E90FF");
+//! assert_eq!(DODGER_BLUE, HexColor::rgb(30, 144, 255));
+//! ```
+//!
 //! Flexible constructors:
 //!
 //! ```
@@ -66,6 +75,18 @@
 //! );
 //! ```
 //!
+//! Colors with floating-point components, like [`Hsl`] or [`LinearRgba`],
+//! are converted to the nearest `HexColor`, with out-of-range channels
+//! clamped. A NaN component never makes it into the result: every channel
+//! computed from it becomes `0`, which, for color spaces that mix their
+//! components, makes the color black.
+//!
+//! ```
+//! use hex_color::{HexColor, Hsl};
+//!
+//! assert_eq!(HexColor::from_hsl(Hsl::new(0.0, 0.0, f32::NAN)), HexColor::BLACK);
+//! ```
+//!
 //! ## With [`rand`](::rand)
 //!
 //! Using `rand` + `std` features to generate random colors via [`rand`](::rand)
@@ -164,13 +185,21 @@
 //!
 //! # Features
 //!
+//! * `libm` provides the floating-point math that `std` would otherwise
+//!   provide, through the [`libm`](https://docs.rs/libm) crate, so that the
+//!   APIs below that need it are also available in `no_std` builds.
 //! * `rand` enables out-of-the-box compatability with the [`rand`](::rand)
 //!   crate.
 //! * `serde` enables serialization and deserialization with the
 //!   [`serde`](::serde) crate.
-//! * `std` enables [`std::error::Error`] on [`ParseHexColorError`]. Otherwise,
-//!   it's needed with `rand` for [`HexColor::random_rgb`],
-//!   [`HexColor::random_rgba`], and, of course,
+//! * `std` enables [`std::error::Error`] on [`ParseHexColorError`] and
+//!   [`ParseCssColorError`]. Either it or `libm` is needed for every API built
+//!   on floating-point math: [`HexColor::parse_css`], the [`Lab`], [`Lch`],
+//!   [`Oklab`], [`Oklch`], [`Xyz`], [`DisplayP3`], and [`Rec2020`] color
+//!   spaces, color temperatures, APCA contrast,
+//!   [`HexColor::adjust_for_contrast`], the [`delta_e`] module, and
+//!   [`BlendMode`]. Otherwise, it's needed with `rand` for
+//!   [`HexColor::random_rgb`], [`HexColor::random_rgba`], and, of course,
 //!   [`rand::random`](::rand::random).
 //!
 //! *Note*: Only the `std` feature is enabled by default.
@@ -184,18 +213,73 @@
 // This is a necessary evil for "r", "g", "b", "a", and more:
 #![allow(clippy::many_single_char_names, clippy::similar_names)]
 
+#[cfg(any(feature = "std", feature = "libm"))]
+mod apca;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod blend;
+mod cmyk;
+mod composite;
+mod contrast;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod convert;
+mod css;
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub mod delta_e;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod gamut;
+mod hsl;
+mod hsv;
+mod hwb;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod lab;
+mod linear;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+pub mod named;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod oklab;
+mod premultiplied;
 #[cfg(feature = "rand")]
 mod rand;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod temperature;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod xyz;
+mod ycbcr;
 
 use core::fmt;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-use core::str::{Bytes, FromStr};
-
+use core::str::FromStr;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::blend::BlendMode;
+pub use self::cmyk::{BlackGeneration, Cmyk};
+pub use self::composite::CompositeOp;
+pub use self::contrast::{Level, TextSize};
+pub use self::css::ParseCssColorError;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::delta_e::Metric;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::gamut::{DisplayP3, GamutMapping, Rec2020};
+pub use self::hsl::Hsl;
+pub use self::hsv::Hsv;
+pub use self::hwb::Hwb;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::lab::{Lab, Lch};
+pub use self::linear::LinearRgba;
+pub use self::named::NameSet;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::oklab::{Oklab, Oklch};
+pub use self::premultiplied::PremultipliedHexColor;
 #[cfg(feature = "serde")]
 #[doc(inline)]
 pub use self::serde::{rgb, rgba, u24, u32};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use self::xyz::{WhitePoint, Xyz};
+pub use self::ycbcr::{YCbCr, YCbCrMatrix, YCbCrRange};
 
 /// An RGBA color.
 ///
@@ -223,7 +307,9 @@ impl HexColor {
     pub const CYAN: HexColor = HexColor::rgb(0, 255, 255);
     /// Solid gray; American spelling of grey. RGBA is `(128, 128, 128, 255)`.
     pub const GRAY: HexColor = HexColor::achromatic(128);
-    /// Solid green. RGBA is `(0, 0, 255, 255)`.
+    /// Solid green. RGBA is `(0, 255, 0, 255)`.
+    ///
+    /// This is what CSS calls `lime`; CSS `green` is [`named::GREEN`].
     pub const GREEN: HexColor = HexColor::rgb(0, 255, 0);
     /// Solid grey; British spelling of gray. RGBA is `(128, 128, 128, 255)`.
     pub const GREY: HexColor = HexColor::achromatic(128);
@@ -352,93 +438,15 @@ impl HexColor {
     // Parsing
     ////////////////////////////////////////////////////////////////////////////
 
+    #[doc(hidden)]
     #[must_use]
-    unsafe fn parse_shorthand(bytes: &mut Bytes, has_alpha: bool) -> Option<HexColor> {
-        unsafe fn parse_single_hex_value(bytes: &mut Bytes) -> Option<u8> {
-            match bytes.next().unwrap_unchecked() {
-                b'0' => Some(0x00),
-                b'1' => Some(0x11),
-                b'2' => Some(0x22),
-                b'3' => Some(0x33),
-                b'4' => Some(0x44),
-                b'5' => Some(0x55),
-                b'6' => Some(0x66),
-                b'7' => Some(0x77),
-                b'8' => Some(0x88),
-                b'9' => Some(0x99),
-                b'a' | b'A' => Some(0xAA),
-                b'b' | b'B' => Some(0xBB),
-                b'c' | b'C' => Some(0xCC),
-                b'd' | b'D' => Some(0xDD),
-                b'e' | b'E' => Some(0xEE),
-                b'f' | b'F' => Some(0xFF),
-                _ => None,
-            }
-        }
-
-        let r = parse_single_hex_value(bytes)?;
-        let g = parse_single_hex_value(bytes)?;
-        let b = parse_single_hex_value(bytes)?;
-        let a = if has_alpha {
-            parse_single_hex_value(bytes)?
-        } else {
-            u8::MAX
-        };
-        Some(HexColor::rgba(r, g, b, a))
-    }
-
-    #[must_use]
-    unsafe fn parse_full(bytes: &mut Bytes, has_alpha: bool) -> Option<HexColor> {
-        const HEX_RADIX: u32 = 16;
-
-        unsafe fn parse_double_hex_value(bytes: &mut Bytes) -> Option<u8> {
-            let buf = [
-                bytes.next().unwrap_unchecked(),
-                bytes.next().unwrap_unchecked(),
-            ];
-            let s = core::str::from_utf8_unchecked(&buf);
-            u8::from_str_radix(s, HEX_RADIX).ok()
-        }
-
-        let r = parse_double_hex_value(bytes)?;
-        let g = parse_double_hex_value(bytes)?;
-        let b = parse_double_hex_value(bytes)?;
-        let a = if has_alpha {
-            parse_double_hex_value(bytes)?
-        } else {
-            u8::MAX
-        };
-        Some(HexColor::rgba(r, g, b, a))
-    }
-
-    fn parse_internals(s: &str, mode: ParseMode) -> Result<HexColor, ParseHexColorError> {
-        macro_rules! err {
-            ($variant:ident) => {{
-                return Err(ParseHexColorError::$variant);
-            }};
-        }
-
-        let mut bytes = s.bytes();
-        match bytes.next() {
-            Some(b'#') => {}
-            Some(_) => err!(InvalidFormat),
-            None => err!(Empty),
+    #[inline]
+    #[track_caller]
+    pub const fn __parse_for_macro(s: &str) -> HexColor {
+        match HexColor::parse_const(s) {
+            Ok(color) => color,
+            Err(err) => panic!("{}", err.description()),
         }
-        let has_alpha = matches!(s.len(), 5 | 9);
-        let opt = match (s.len(), mode) {
-            (4, ParseMode::Rgb | ParseMode::Any) | (5, ParseMode::Rgba | ParseMode::Any) => {
-                // SAFETY: `bytes` will have either `3` or `4` bytes left and
-                // `has_alpha` is synchronized.
-                unsafe { HexColor::parse_shorthand(&mut bytes, has_alpha) }
-            }
-            (7, ParseMode::Rgb | ParseMode::Any) | (9, ParseMode::Rgba | ParseMode::Any) => {
-                // SAFETY: `bytes` will have either `6` or `8` bytes left and
-                // `has_alpha` is synchronized.
-                unsafe { HexColor::parse_full(&mut bytes, has_alpha) }
-            }
-            _ => err!(InvalidFormat),
-        };
-        opt.ok_or(ParseHexColorError::InvalidDigit)
     }
 
     /// Parses an RGB(A) hex code.
@@ -460,14 +468,16 @@ impl HexColor {
     /// # Errors
     ///
     /// - [`Empty`] when the input is empty.
-    /// - [`InvalidFormat`] when the input is a malformed length or lacks a
-    ///   leading `#`. If you suspect there might be whitespace in the input,
-    ///   consider calling [`str::trim`] first.
+    /// - [`MissingPrefix`] when the input lacks a leading `#`. If you suspect
+    ///   there might be whitespace in the input, consider calling [`str::trim`]
+    ///   first.
+    /// - [`InvalidLength`] when the input is a malformed length.
     /// - [`InvalidDigit`] when the format seems correct but one of the
     ///   characters is an invalid hexadecimal digit.
     ///
     /// [`Empty`]: ParseHexColorError::Empty
-    /// [`InvalidFormat`]: ParseHexColorError::InvalidFormat
+    /// [`MissingPrefix`]: ParseHexColorError::MissingPrefix
+    /// [`InvalidLength`]: ParseHexColorError::InvalidLength
     /// [`InvalidDigit`]: ParseHexColorError::InvalidDigit
     ///
     /// # Examples
@@ -492,7 +502,7 @@ impl HexColor {
     /// ```
     #[inline]
     pub fn parse(s: &str) -> Result<HexColor, ParseHexColorError> {
-        HexColor::parse_internals(s, ParseMode::Any)
+        HexColor::parse_with(s, ParseOptions::new())
     }
 
     /// Parses an RGB hex code.
@@ -512,17 +522,19 @@ impl HexColor {
     /// # Errors
     ///
     /// - [`Empty`] when the input is empty.
-    /// - [`InvalidFormat`] when the input is a malformed length or lacks a
-    ///   leading `#`. If you suspect there might be whitespace in the input,
-    ///   consider calling [`str::trim`] first.
+    /// - [`MissingPrefix`] when the input lacks a leading `#`. If you suspect
+    ///   there might be whitespace in the input, consider calling [`str::trim`]
+    ///   first.
+    /// - [`InvalidLength`] when the input is a malformed length.
     /// - [`InvalidDigit`] when the format seems correct but one of the
     ///   characters is an invalid hexadecimal digit.
     ///
-    /// *Note*: a valid RGBA input will return an [`InvalidFormat`]. Use
+    /// *Note*: a valid RGBA input will return an [`InvalidLength`]. Use
     /// [`parse_rgba`] or [`parse`] instead if that behavior is not desired.
     ///
     /// [`Empty`]: ParseHexColorError::Empty
-    /// [`InvalidFormat`]: ParseHexColorError::InvalidFormat
+    /// [`MissingPrefix`]: ParseHexColorError::MissingPrefix
+    /// [`InvalidLength`]: ParseHexColorError::InvalidLength
     /// [`InvalidDigit`]: ParseHexColorError::InvalidDigit
     ///
     /// # Examples
@@ -541,7 +553,7 @@ impl HexColor {
     /// ```
     #[inline]
     pub fn parse_rgb(s: &str) -> Result<HexColor, ParseHexColorError> {
-        HexColor::parse_internals(s, ParseMode::Rgb)
+        HexColor::parse_with(s, ParseOptions::new().with_mode(ParseMode::Rgb))
     }
 
     /// Parses an RGBA hex code.
@@ -561,18 +573,20 @@ impl HexColor {
     /// # Errors
     ///
     /// - [`Empty`] when the input is empty.
-    /// - [`InvalidFormat`] when the input is a malformed length or lacks a
-    ///   leading `#`. If you suspect there might be whitespace in the input,
-    ///   consider calling [`str::trim`] first.
+    /// - [`MissingPrefix`] when the input lacks a leading `#`. If you suspect
+    ///   there might be whitespace in the input, consider calling [`str::trim`]
+    ///   first.
+    /// - [`InvalidLength`] when the input is a malformed length.
     /// - [`InvalidDigit`] when the format seems correct but one of the
     ///   characters is an invalid hexadecimal digit.
     ///
     /// **Note**: a valid RGB input (without an alpha value) will return an
-    /// [`InvalidFormat`]. Use [`parse_rgb`] or [`parse`] instead if that
+    /// [`InvalidLength`]. Use [`parse_rgb`] or [`parse`] instead if that
     /// behavior is not desired.
     ///
     /// [`Empty`]: ParseHexColorError::Empty
-    /// [`InvalidFormat`]: ParseHexColorError::InvalidFormat
+    /// [`MissingPrefix`]: ParseHexColorError::MissingPrefix
+    /// [`InvalidLength`]: ParseHexColorError::InvalidLength
     /// [`InvalidDigit`]: ParseHexColorError::InvalidDigit
     ///
     /// # Examples
@@ -591,7 +605,256 @@ impl HexColor {
     /// ```
     #[inline]
     pub fn parse_rgba(s: &str) -> Result<HexColor, ParseHexColorError> {
-        HexColor::parse_internals(s, ParseMode::Rgba)
+        HexColor::parse_with(s, ParseOptions::new().with_mode(ParseMode::Rgba))
+    }
+
+    /// Parses an RGB(A) hex code in a `const` context.
+    ///
+    /// This accepts and rejects exactly the same inputs as [`parse`], so it
+    /// can be used to build tables of colors from strings in `const` and
+    /// `static` items. When the input is a literal, the [`hex!`] macro is
+    /// often more convenient.
+    ///
+    /// [`parse`]: HexColor::parse
+    /// [`hex!`]: crate::hex!
+    ///
+    /// # Errors
+    ///
+    /// See [`parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// const PALETTE: [Result<HexColor, hex_color::ParseHexColorError>; 2] = [
+    ///     HexColor::parse_const("#F0F8FF"),
+    ///     HexColor::parse_const("#FAEBD780"),
+    /// ];
+    ///
+    /// assert_eq!(PALETTE[0], Ok(HexColor::rgb(0xF0, 0xF8, 0xFF)));
+    /// assert_eq!(PALETTE[1], Ok(HexColor::rgba(0xFA, 0xEB, 0xD7, 0x80)));
+    /// ```
+    #[inline]
+    pub const fn parse_const(s: &str) -> Result<HexColor, ParseHexColorError> {
+        HexColor::parse_with(s, ParseOptions::new())
+    }
+
+    /// Parses an RGB hex code in a `const` context.
+    ///
+    /// This accepts and rejects exactly the same inputs as [`parse_rgb`].
+    ///
+    /// [`parse_rgb`]: HexColor::parse_rgb
+    ///
+    /// # Errors
+    ///
+    /// See [`parse_rgb`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// const AQUAMARINE: HexColor = match HexColor::parse_rgb_const("#7FFFD4") {
+    ///     Ok(color) => color,
+    ///     Err(_) => panic!("invalid color"),
+    /// };
+    ///
+    /// assert_eq!(AQUAMARINE, HexColor::rgb(0x7F, 0xFF, 0xD4));
+    /// ```
+    #[inline]
+    pub const fn parse_rgb_const(s: &str) -> Result<HexColor, ParseHexColorError> {
+        HexColor::parse_with(s, ParseOptions::new().with_mode(ParseMode::Rgb))
+    }
+
+    /// Parses an RGBA hex code in a `const` context.
+    ///
+    /// This accepts and rejects exactly the same inputs as [`parse_rgba`].
+    ///
+    /// [`parse_rgba`]: HexColor::parse_rgba
+    ///
+    /// # Errors
+    ///
+    /// See [`parse_rgba`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseHexColorError};
+    ///
+    /// const BISQUE: Result<HexColor, ParseHexColorError> =
+    ///     HexColor::parse_rgba_const("#FFE4C480");
+    /// const OPAQUE_BISQUE: Result<HexColor, ParseHexColorError> =
+    ///     HexColor::parse_rgba_const("#FFE4C4");
+    ///
+    /// assert_eq!(BISQUE, Ok(HexColor::rgba(0xFF, 0xE4, 0xC4, 0x80)));
+    /// assert_eq!(
+    ///     OPAQUE_BISQUE,
+    ///     Err(ParseHexColorError::InvalidLength { length: 6 }),
+    /// );
+    /// ```
+    #[inline]
+    pub const fn parse_rgba_const(s: &str) -> Result<HexColor, ParseHexColorError> {
+        HexColor::parse_with(s, ParseOptions::new().with_mode(ParseMode::Rgba))
+    }
+
+    /// Parses an RGB(A) hex code according to the given [`ParseOptions`].
+    ///
+    /// [`ParseOptions::new`] is exactly as strict as [`parse`], so only the
+    /// options that are changed make the parsing more lenient (or stricter).
+    /// This is a `const fn`, so it can also be used in `const` and `static`
+    /// items.
+    ///
+    /// [`parse`]: HexColor::parse
+    ///
+    /// # Errors
+    ///
+    /// - [`Empty`] when the input is empty, after any trimming.
+    /// - [`MissingPrefix`] when the input lacks a required leading `#` (or
+    ///   `0x`, if allowed).
+    /// - [`InvalidLength`] when the input is a length not allowed by the
+    ///   options.
+    /// - [`InvalidDigit`] when the format seems correct but one of the
+    ///   characters is an invalid hexadecimal digit.
+    ///
+    /// [`Empty`]: ParseHexColorError::Empty
+    /// [`MissingPrefix`]: ParseHexColorError::MissingPrefix
+    /// [`InvalidLength`]: ParseHexColorError::InvalidLength
+    /// [`InvalidDigit`]: ParseHexColorError::InvalidDigit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// # fn main() -> Result<(), hex_color::ParseHexColorError> {
+    /// let options = ParseOptions::lenient();
+    ///
+    /// let magenta = HexColor::rgb(0xFF, 0x00, 0xAA);
+    /// assert_eq!(HexColor::parse_with("ff00aa", options)?, magenta);
+    /// assert_eq!(HexColor::parse_with(" #FF00AA ", options)?, magenta);
+    /// assert_eq!(HexColor::parse_with("0xFF00AA", options)?, magenta);
+    /// assert_eq!(HexColor::parse_with("#ff00aa;", options)?, magenta);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Options can also make parsing stricter than [`parse`]:
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseHexColorError, ParseMode, ParseOptions};
+    ///
+    /// let options = ParseOptions::new()
+    ///     .with_mode(ParseMode::Rgb)
+    ///     .allow_shorthand(false);
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_with("#F0F", options),
+    ///     Err(ParseHexColorError::InvalidLength { length: 3 }),
+    /// );
+    /// assert_eq!(
+    ///     HexColor::parse_with("#FF00FF", options),
+    ///     Ok(HexColor::MAGENTA),
+    /// );
+    /// ```
+    pub const fn parse_with(
+        s: &str,
+        options: ParseOptions,
+    ) -> Result<HexColor, ParseHexColorError> {
+        const fn hex_digit(byte: u8) -> Option<u8> {
+            match byte {
+                b'0'..=b'9' => Some(byte - b'0'),
+                b'a'..=b'f' => Some(byte - b'a' + 0xA),
+                b'A'..=b'F' => Some(byte - b'A' + 0xA),
+                _ => None,
+            }
+        }
+
+        macro_rules! err {
+            ($variant:ident) => {{
+                return Err(ParseHexColorError::$variant);
+            }};
+        }
+
+        // Every byte before an invalid digit is ASCII, so `index` is always on
+        // a `char` boundary of `s`.
+        const fn invalid_digit(s: &str, index: usize) -> ParseHexColorError {
+            let bytes = s.as_bytes();
+            let (len, mut code_point) = match bytes[index] {
+                byte @ 0x00..=0x7F => (1, byte as u32),
+                byte @ 0x80..=0xDF => (2, (byte & 0x1F) as u32),
+                byte @ 0xE0..=0xEF => (3, (byte & 0x0F) as u32),
+                byte => (4, (byte & 0x07) as u32),
+            };
+            let mut i = 1;
+            while i < len {
+                code_point = code_point << 6 | (bytes[index + i] & 0x3F) as u32;
+                i += 1;
+            }
+            let found = match char::from_u32(code_point) {
+                Some(c) => c,
+                None => char::REPLACEMENT_CHARACTER,
+            };
+            ParseHexColorError::InvalidDigit { index, found }
+        }
+
+        let mut bytes = s.as_bytes();
+        let mut offset = 0;
+        if options.trim_whitespace {
+            offset = bytes.len() - bytes.trim_ascii_start().len();
+            bytes = bytes.trim_ascii();
+        }
+        if options.trailing_semicolon {
+            if let [rest @ .., b';'] = bytes {
+                bytes = rest;
+                if options.trim_whitespace {
+                    bytes = bytes.trim_ascii_end();
+                }
+            }
+        }
+
+        let (digits, offset) = match bytes {
+            [] => err!(Empty),
+            [b'#', digits @ ..] => (digits, offset + 1),
+            [b'0', b'x' | b'X', digits @ ..] if options.hex_prefix => (digits, offset + 2),
+            digits if !options.require_hash => (digits, offset),
+            _ => {
+                return Err(ParseHexColorError::MissingPrefix {
+                    accepts_0x: options.hex_prefix,
+                })
+            }
+        };
+        let (channels, width) = match (digits.len(), options.mode) {
+            (3, ParseMode::Rgb | ParseMode::Any) if options.shorthand => (3, 1),
+            (4, ParseMode::Rgba | ParseMode::Any) if options.shorthand => (4, 1),
+            (6, ParseMode::Rgb | ParseMode::Any) => (3, 2),
+            (8, ParseMode::Rgba | ParseMode::Any) => (4, 2),
+            (length, _) => return Err(ParseHexColorError::InvalidLength { length }),
+        };
+
+        // `[r, g, b, a]`, where alpha stays opaque unless it's given.
+        let mut rgba = [u8::MAX; 4];
+        let mut i = 0;
+        while i < channels {
+            let start = i * width;
+            let Some(high) = hex_digit(digits[start]) else {
+                return Err(invalid_digit(s, offset + start));
+            };
+            // Shorthand digits are doubled, e.g., `#F00` is `#FF0000`.
+            let low = if width == 1 {
+                high
+            } else {
+                match hex_digit(digits[start + 1]) {
+                    Some(digit) => digit,
+                    None => return Err(invalid_digit(s, offset + start + 1)),
+                }
+            };
+            rgba[i] = high << 4 | low;
+            i += 1;
+        }
+
+        let [r, g, b, a] = rgba;
+        Ok(HexColor::rgba(r, g, b, a))
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -1462,12 +1725,11 @@ impl HexColor {
     #[inline]
     #[must_use]
     #[track_caller]
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn scale(self, f: f32) -> Self {
         let (r, g, b, a) = self.split_rgba();
-        let r = (f32::from(r) * f).min(255.0).round() as u8;
-        let g = (f32::from(g) * f).min(255.0).round() as u8;
-        let b = (f32::from(b) * f).min(255.0).round() as u8;
+        let r = round_to_u8(f32::from(r) * f);
+        let g = round_to_u8(f32::from(g) * f);
+        let b = round_to_u8(f32::from(b) * f);
         HexColor::rgba(r, g, b, a)
     }
 
@@ -1726,6 +1988,37 @@ impl From<HexColor> for u32 {
     }
 }
 
+/// Rounds `value` half away from zero and saturates it to a `u8`, mapping NaN
+/// to `0`.
+///
+/// [`f32::round`] isn't available without `std`, but since truncating an `f32`
+/// in the range of a `u8` and subtracting the result is exact, rounding only
+/// needs a comparison.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_to_u8(value: f32) -> u8 {
+    // `f32::min` would turn NaN into `255.0`.
+    if value.is_nan() {
+        return 0;
+    }
+    let value = value.min(255.0);
+    let truncated = value as u8;
+    if value - f32::from(truncated) >= 0.5 {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Wraps a hue in degrees into the range `0.0..360.0`.
+fn normalize_hue(hue: f32) -> f32 {
+    let hue = hue % 360.0;
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Parsing Details
 ////////////////////////////////////////////////////////////////////////////////
@@ -1737,17 +2030,268 @@ impl FromStr for HexColor {
     /// refer to that function's documentation.
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        HexColor::parse_internals(s, ParseMode::Any)
+        HexColor::parse_with(s, ParseOptions::new())
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-enum ParseMode {
+/// Which channels a hexadecimal color may have when parsing with
+/// [`ParseOptions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ParseMode {
+    /// Accept both RGB and RGBA colors, like [`HexColor::parse`].
+    #[default]
     Any,
+    /// Accept only RGB colors, like [`HexColor::parse_rgb`].
     Rgb,
+    /// Accept only RGBA colors, like [`HexColor::parse_rgba`].
     Rgba,
 }
 
+/// Options for parsing hexadecimal colors through [`HexColor::parse_with`].
+///
+/// By default, the options are as strict as [`HexColor::parse`]: a leading `#`
+/// is required, no surrounding characters are allowed, and any of `#RGB`,
+/// `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` is accepted.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, ParseOptions};
+///
+/// let options = ParseOptions::new()
+///     .require_hash(false)
+///     .trim_whitespace(true);
+///
+/// assert_eq!(HexColor::parse_with(" 0FF ", options), Ok(HexColor::CYAN));
+/// assert_eq!(HexColor::parse_with("#0FF", options), Ok(HexColor::CYAN));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+// Each of these is an independent switch, so a bitflag-esque enum per option
+// would only add noise to the builder.
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    mode: ParseMode,
+    shorthand: bool,
+    require_hash: bool,
+    hex_prefix: bool,
+    trim_whitespace: bool,
+    trailing_semicolon: bool,
+}
+
+impl ParseOptions {
+    /// Constructs new `ParseOptions` that are exactly as strict as
+    /// [`HexColor::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_with("#DDA0DD", ParseOptions::new()),
+    ///     HexColor::parse("#DDA0DD"),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        ParseOptions {
+            mode: ParseMode::Any,
+            shorthand: true,
+            require_hash: true,
+            hex_prefix: false,
+            trim_whitespace: false,
+            trailing_semicolon: false,
+        }
+    }
+
+    /// Constructs new `ParseOptions` that accept colors the way people tend to
+    /// paste them: with or without a leading `#`, with a `0x` prefix,
+    /// surrounded by whitespace, or followed by a `;`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_with("  0x00FFFF; ", ParseOptions::lenient()),
+    ///     Ok(HexColor::CYAN),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn lenient() -> Self {
+        ParseOptions::new()
+            .require_hash(false)
+            .allow_0x_prefix(true)
+            .trim_whitespace(true)
+            .allow_trailing_semicolon(true)
+    }
+
+    /// Sets which channels the color may have. The default is
+    /// [`ParseMode::Any`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseHexColorError, ParseMode, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().with_mode(ParseMode::Rgba);
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_with("#0FF", options),
+    ///     Err(ParseHexColorError::InvalidLength { length: 3 }),
+    /// );
+    /// assert_eq!(HexColor::parse_with("#0FFF", options), Ok(HexColor::CYAN));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the shorthand `#RGB` and `#RGBA` forms are accepted. The
+    /// default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseHexColorError, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().allow_shorthand(false);
+    ///
+    /// assert_eq!(
+    ///     HexColor::parse_with("#0FF", options),
+    ///     Err(ParseHexColorError::InvalidLength { length: 3 }),
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn allow_shorthand(mut self, allow: bool) -> Self {
+        self.shorthand = allow;
+        self
+    }
+
+    /// Sets whether a leading `#` is required. The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().require_hash(false);
+    ///
+    /// assert_eq!(HexColor::parse_with("FFFF00", options), Ok(HexColor::YELLOW));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn require_hash(mut self, require: bool) -> Self {
+        self.require_hash = require;
+        self
+    }
+
+    /// Sets whether a leading `0x` (or `0X`) is accepted in place of `#`. The
+    /// default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().allow_0x_prefix(true);
+    ///
+    /// assert_eq!(HexColor::parse_with("0xFFFF00", options), Ok(HexColor::YELLOW));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn allow_0x_prefix(mut self, allow: bool) -> Self {
+        self.hex_prefix = allow;
+        self
+    }
+
+    /// Sets whether leading and trailing ASCII whitespace is ignored. The
+    /// default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().trim_whitespace(true);
+    ///
+    /// assert_eq!(HexColor::parse_with("\t#FF0\n", options), Ok(HexColor::YELLOW));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn trim_whitespace(mut self, trim: bool) -> Self {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Sets whether a single trailing `;`, as found at the end of CSS
+    /// declarations, is ignored. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().allow_trailing_semicolon(true);
+    ///
+    /// assert_eq!(HexColor::parse_with("#FF0;", options), Ok(HexColor::YELLOW));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn allow_trailing_semicolon(mut self, allow: bool) -> Self {
+        self.trailing_semicolon = allow;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    /// Equivalent to [`ParseOptions::new`].
+    #[inline]
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+/// Parses an RGB(A) hex code at compile time.
+///
+/// The input is held to the same rules as [`HexColor::parse_const`], but
+/// instead of returning a [`ParseHexColorError`], invalid input fails to
+/// compile. Since the result is a constant expression, `hex!` can be used to
+/// initialize `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{hex, HexColor};
+///
+/// const DODGER_BLUE: HexColor = hex!("#1E90FF");
+/// assert_eq!(DODGER_BLUE, HexColor::rgb(0x1E, 0x90, 0xFF));
+///
+/// static TRANSLUCENT_RED: HexColor = hex!("#F008");
+/// assert_eq!(TRANSLUCENT_RED, HexColor::rgba(0xFF, 0x00, 0x00, 0x88));
+/// ```
+///
+/// Malformed input is a compile-time error:
+///
+/// ```compile_fail
+/// use hex_color::{hex, HexColor};
+///
+/// const NOT_A_COLOR: HexColor = hex!("#GGGGGG");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($s:expr $(,)?) => {{
+        const COLOR: $crate::HexColor = $crate::HexColor::__parse_for_macro($s);
+        COLOR
+    }};
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Display
 ////////////////////////////////////////////////////////////////////////////////
@@ -1898,27 +2442,123 @@ pub enum Case {
 /// Among other causes, `ParseHexColorError` can be thrown because of leading
 /// or trailing whitespace in the string e.g., when it is obtained from user
 /// input. Using the [`str::trim()`] method ensures that no whitespace remains
-/// before parsing.
+/// before parsing, as does [`ParseOptions::trim_whitespace`].
+///
+/// # Examples
+///
+/// Invalid digits are reported with their byte offset in the original input,
+/// which makes it possible to point at the offending column:
+///
+/// ```
+/// use hex_color::{HexColor, ParseHexColorError, ParseOptions};
+///
+/// assert_eq!(
+///     HexColor::parse_with("  #12x456", ParseOptions::lenient()),
+///     Err(ParseHexColorError::InvalidDigit {
+///         index: 5,
+///         found: 'x',
+///     }),
+/// );
+/// assert_eq!(
+///     HexColor::parse("#12é"),
+///     Err(ParseHexColorError::InvalidDigit {
+///         index: 3,
+///         found: 'é',
+///     }),
+/// );
+/// assert_eq!(
+///     HexColor::parse("FF0000"),
+///     Err(ParseHexColorError::MissingPrefix { accepts_0x: false }),
+/// );
+/// ```
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum ParseHexColorError {
     /// The input was empty.
     Empty,
-    /// The string is of a malformed length or does not start with `#`.
-    InvalidFormat,
+    /// The string does not start with `#` (or `0x`, if allowed by
+    /// [`ParseOptions`]).
+    MissingPrefix {
+        /// Whether `0x` would have been accepted, too, i.e., whether
+        /// [`ParseOptions::allow_0x_prefix`] was set.
+        accepts_0x: bool,
+    },
+    /// The string is of a malformed length.
+    InvalidLength {
+        /// The number of bytes following the prefix.
+        length: usize,
+    },
     /// The format was presumably correct, but one of the digits wasn't
     /// hexadecimal.
-    InvalidDigit,
+    InvalidDigit {
+        /// The byte offset of the invalid digit in the input.
+        index: usize,
+        /// The invalid digit itself.
+        found: char,
+    },
+}
+
+impl ParseHexColorError {
+    const fn description(self) -> &'static str {
+        match self {
+            ParseHexColorError::Empty => "cannot parse hex color from empty string",
+            ParseHexColorError::MissingPrefix { accepts_0x: false } => {
+                "hexadecimal color is missing a leading `#`"
+            }
+            ParseHexColorError::MissingPrefix { accepts_0x: true } => {
+                "hexadecimal color is missing a leading `#` or `0x`"
+            }
+            ParseHexColorError::InvalidLength { .. } => "invalid hexadecimal color length",
+            ParseHexColorError::InvalidDigit { .. } => "invalid hexadecimal digit",
+        }
+    }
 }
 
 impl fmt::Display for ParseHexColorError {
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, ParseOptions};
+    ///
+    /// let err = HexColor::parse("#00FF0G").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid hexadecimal digit 'G' at index 6");
+    ///
+    /// let err = HexColor::parse("#00FF0").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid hexadecimal color length of 5 bytes",
+    /// );
+    ///
+    /// // The length is in bytes, like the index of an invalid digit:
+    /// let err = HexColor::parse("#é").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid hexadecimal color length of 2 bytes",
+    /// );
+    ///
+    /// // The message names every prefix that would have been accepted:
+    /// let err = HexColor::parse("00FF00").unwrap_err();
+    /// assert_eq!(err.to_string(), "hexadecimal color is missing a leading `#`");
+    ///
+    /// let options = ParseOptions::new().allow_0x_prefix(true);
+    /// let err = HexColor::parse_with("00FF00", options).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "hexadecimal color is missing a leading `#` or `0x`",
+    /// );
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let data = match self {
-            ParseHexColorError::Empty => "cannot parse hex color from empty string",
-            ParseHexColorError::InvalidFormat => "invalid hexadecimal color format",
-            ParseHexColorError::InvalidDigit => "invalid hexadecimal digit",
-        };
-        f.write_str(data)
+        let description = self.description();
+        match *self {
+            ParseHexColorError::InvalidLength { length } => {
+                let unit = if length == 1 { "byte" } else { "bytes" };
+                write!(f, "{description} of {length} {unit}")
+            }
+            ParseHexColorError::InvalidDigit { index, found } => {
+                write!(f, "{description} {found:?} at index {index}")
+            }
+            _ => f.write_str(description),
+        }
     }
 }
 
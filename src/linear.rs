@@ -0,0 +1,220 @@
+use crate::{round_to_u8, HexColor};
+
+/// A color in linear-light sRGB, with each component, including alpha, as an
+/// `f32` in the range `0.0..=1.0`.
+///
+/// The components of a [`HexColor`] are gamma-encoded, i.e., spaced out
+/// roughly evenly in perceived brightness rather than in physical light. That
+/// makes arithmetic on them, like [`HexColor::scale`], only an approximation
+/// of what happens to light: averaging black and white gives `#808080`, which
+/// emits only about 22% of the light of white, not 50%. In linear light, sums
+/// and products of colors are physically meaningful.
+///
+/// The alpha component is never gamma-encoded, so it's only rescaled.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, LinearRgba};
+///
+/// let black = HexColor::BLACK.to_linear();
+/// let white = HexColor::WHITE.to_linear();
+/// let half = LinearRgba::new(
+///     (black.r + white.r) / 2.0,
+///     (black.g + white.g) / 2.0,
+///     (black.b + white.b) / 2.0,
+///     1.0,
+/// );
+/// assert_eq!(HexColor::from_linear(half), HexColor::achromatic(188));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct LinearRgba {
+    /// The red component of the color.
+    pub r: f32,
+    /// The green component of the color.
+    pub g: f32,
+    /// The blue component of the color.
+    pub b: f32,
+    /// The alpha component of the color (`0.0` is transparent, `1.0` is
+    /// opaque).
+    pub a: f32,
+}
+
+impl LinearRgba {
+    /// Constructs a new `LinearRgba` from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> LinearRgba {
+        LinearRgba { r, g, b, a }
+    }
+}
+
+impl HexColor {
+    /// Converts the color to linear-light sRGB with the exact sRGB transfer
+    /// function.
+    ///
+    /// Since there are only 256 possible values per component, this is a
+    /// table lookup of the correctly rounded results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, LinearRgba};
+    ///
+    /// assert_eq!(HexColor::WHITE.to_linear(), LinearRgba::new(1.0, 1.0, 1.0, 1.0));
+    /// assert_eq!(HexColor::CLEAR.to_linear(), LinearRgba::new(0.0, 0.0, 0.0, 0.0));
+    ///
+    /// let gray = HexColor::achromatic(128).to_linear();
+    /// assert!((gray.r - 0.2158605).abs() < 1e-7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_linear(self) -> LinearRgba {
+        LinearRgba {
+            r: DECODE[usize::from(self.r)],
+            g: DECODE[usize::from(self.g)],
+            b: DECODE[usize::from(self.b)],
+            a: f32::from(self.a) / 255.0,
+        }
+    }
+
+    /// Converts a linear-light sRGB color to the nearest `HexColor` with the
+    /// exact sRGB transfer function.
+    ///
+    /// Each component is correctly rounded, i.e., it's the `u8` that's closest
+    /// to the exact gamma-encoded value. Values outside of the range
+    /// `0.0..=1.0` are clamped, and NaN becomes `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, LinearRgba};
+    ///
+    /// let color = LinearRgba::new(0.5, 2.0, -1.0, 0.5);
+    /// assert_eq!(HexColor::from_linear(color), HexColor::rgba(188, 255, 0, 128));
+    /// ```
+    ///
+    /// Converting to [`LinearRgba`] and back is lossless for every color:
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// for n in 0..=u8::MAX {
+    ///     let color = HexColor::rgba(n, n, n, n);
+    ///     assert_eq!(HexColor::from_linear(color.to_linear()), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_linear(linear: LinearRgba) -> HexColor {
+        HexColor::rgba(
+            encode(linear.r),
+            encode(linear.g),
+            encode(linear.b),
+            round_to_u8(linear.a.max(0.0) * 255.0),
+        )
+    }
+}
+
+impl From<HexColor> for LinearRgba {
+    /// Constructs a new `LinearRgba` from a `HexColor` via
+    /// [`HexColor::to_linear`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_linear()
+    }
+}
+
+impl From<LinearRgba> for HexColor {
+    /// Constructs a new `HexColor` from a `LinearRgba` via
+    /// [`HexColor::from_linear`].
+    #[inline]
+    fn from(linear: LinearRgba) -> Self {
+        HexColor::from_linear(linear)
+    }
+}
+
+/// Gamma-encodes a linear-light component by searching for it among the
+/// linear values halfway between consecutive encoded ones, which needs no
+/// more than eight comparisons.
+#[allow(clippy::cast_possible_truncation)]
+fn encode(c: f32) -> u8 {
+    // There are 255 thresholds, so the result always fits:
+    THRESHOLDS.partition_point(|&threshold| threshold <= c) as u8
+}
+
+/// The linear-light value of every gamma-encoded component, correctly
+/// rounded.
+#[rustfmt::skip]
+static DECODE: [f32; 256] = [
+    0.0, 0.000_303_527, 0.000_607_054, 0.000_910_581, 0.001_214_108, 0.001_517_635, 0.001_821_162, 0.002_124_688_8,
+    0.002_428_216, 0.002_731_742_8, 0.003_035_27, 0.003_346_535_8, 0.003_676_507_4, 0.004_024_717, 0.004_391_442, 0.004_776_953_3,
+    0.005_181_516_5, 0.005_605_391_7, 0.006_048_833, 0.006_512_090_6, 0.006_995_41, 0.007_499_032, 0.008_023_193, 0.008_568_126,
+    0.009_134_059, 0.009_721_218, 0.010_329_823, 0.010_960_094, 0.011_612_245, 0.012_286_488, 0.012_983_032_5, 0.013_702_083,
+    0.014_443_844, 0.015_208_514, 0.015_996_294, 0.016_807_375, 0.017_641_954, 0.018_500_22, 0.019_382_361, 0.020_288_562,
+    0.021_219_01, 0.022_173_885, 0.023_153_367, 0.024_157_632, 0.025_186_86, 0.026_241_222, 0.027_320_892, 0.028_426_04,
+    0.029_556_835, 0.030_713_445, 0.031_896_032, 0.033_104_766, 0.034_339_808, 0.035_601_314, 0.036_889_45, 0.038_204_372,
+    0.039_546_236, 0.040_915_2, 0.042_311_41, 0.043_735_03, 0.045_186_203, 0.046_665_087, 0.048_171_826, 0.049_706_567,
+    0.051_269_457, 0.052_860_647, 0.054_480_277, 0.056_128_49, 0.057_805_43, 0.059_511_237, 0.061_246_052, 0.063_010_015,
+    0.064_803_265, 0.066_625_94, 0.068_478_17, 0.070_360_094, 0.072_271_85, 0.074_213_57, 0.076_185_38, 0.078_187_42,
+    0.080_219_82, 0.082_282_71, 0.084_376_21, 0.086_500_46, 0.088_655_58, 0.090_841_71, 0.093_058_966, 0.095_307_47,
+    0.097_587_35, 0.099_898_726, 0.102_241_73, 0.104_616_486, 0.107_023_105, 0.109_461_71, 0.111_932_43, 0.114_435_375,
+    0.116_970_666, 0.119_538_43, 0.122_138_776, 0.124_771_82, 0.127_437_68, 0.130_136_47, 0.132_868_32, 0.135_633_33,
+    0.138_431_61, 0.141_263_29, 0.144_128_47, 0.147_027_27, 0.149_959_79, 0.152_926_15, 0.155_926_47, 0.158_960_83,
+    0.162_029_37, 0.165_132_2, 0.168_269_4, 0.171_441_11, 0.174_647_4, 0.177_888_42, 0.181_164_25, 0.184_474_99,
+    0.187_820_78, 0.191_201_69, 0.194_617_84, 0.198_069_32, 0.201_556_25, 0.205_078_74, 0.208_636_87, 0.212_230_76,
+    0.215_860_5, 0.219_526_2, 0.223_227_96, 0.226_965_87, 0.230_740_06, 0.234_550_58, 0.238_397_57, 0.242_281_12,
+    0.246_201_32, 0.250_158_28, 0.254_152_1, 0.258_182_85, 0.262_250_66, 0.266_355_6, 0.270_497_8, 0.274_677_3,
+    0.278_894_28, 0.283_148_74, 0.287_440_84, 0.291_770_64, 0.296_138_26, 0.300_543_79, 0.304_987_3, 0.309_468_92,
+    0.313_988_72, 0.318_546_77, 0.323_143_2, 0.327_778_1, 0.332_451_52, 0.337_163_63, 0.341_914_42, 0.346_704_07,
+    0.351_532_6, 0.356_400_13, 0.361_306_8, 0.366_252_6, 0.371_237_7, 0.376_262_13, 0.381_326_02, 0.386_429_43,
+    0.391_572_48, 0.396_755_22, 0.401_977_78, 0.407_240_2, 0.412_542_6, 0.417_885_07, 0.423_267_66, 0.428_690_5,
+    0.434_153_65, 0.439_657_18, 0.445_201_2, 0.450_785_8, 0.456_411_03, 0.462_077, 0.467_783_8, 0.473_531_48,
+    0.479_320_17, 0.485_149_95, 0.491_020_86, 0.496_932_98, 0.502_886_5, 0.508_881_33, 0.514_917_7, 0.520_995_56,
+    0.527_115_1, 0.533_276_4, 0.539_479_5, 0.545_724_45, 0.552_011_43, 0.558_340_4, 0.564_711_5, 0.571_124_85,
+    0.577_580_45, 0.584_078_43, 0.590_618_85, 0.597_201_76, 0.603_827_36, 0.610_495_57, 0.617_206_6, 0.623_960_4,
+    0.630_757_15, 0.637_596_85, 0.644_479_7, 0.651_405_63, 0.658_374_85, 0.665_387_3, 0.672_443_15, 0.679_542_5,
+    0.686_685_3, 0.693_871_74, 0.701_101_9, 0.708_375_75, 0.715_693_5, 0.723_055_1, 0.730_460_76, 0.737_910_4,
+    0.745_404_2, 0.752_942_2, 0.760_524_5, 0.768_151_16, 0.775_822_2, 0.783_537_8, 0.791_297_9, 0.799_102_7,
+    0.806_952_24, 0.814_846_6, 0.822_785_74, 0.830_769_9, 0.838_799, 0.846_873_2, 0.854_992_6, 0.863_157_2,
+    0.871_367_1, 0.879_622_4, 0.887_923_1, 0.896_269_4, 0.904_661_2, 0.913_098_63, 0.921_581_86, 0.930_110_9,
+    0.938_685_7, 0.947_306_5, 0.955_973_3, 0.964_686_3, 0.973_445_3, 0.982_250_6, 0.991_102_1, 1.0,
+];
+
+/// The linear-light value halfway between each pair of consecutive
+/// gamma-encoded components, i.e., the exact value of `(i + 0.5) / 255`
+/// decoded, rounded up to the next `f32`.
+#[rustfmt::skip]
+static THRESHOLDS: [f32; 255] = [
+    0.000_151_763_5, 0.000_455_290_5, 0.000_758_817_5, 0.001_062_344_5, 0.001_365_871_5, 0.001_669_398_5, 0.001_972_925_6, 0.002_276_452_5,
+    0.002_579_979_4, 0.002_883_506_5, 0.003_188_301, 0.003_509_259_5, 0.003_848_315, 0.004_205_748_4, 0.004_581_833, 0.004_976_837_4,
+    0.005_391_024_5, 0.005_824_651, 0.006_277_969_6, 0.006_751_227_7, 0.007_244_668_4, 0.007_758_531, 0.008_293_048_5, 0.008_848_454,
+    0.009_424_971, 0.010_022_826_5, 0.010_642_237, 0.011_283_422, 0.011_946_592_5, 0.012_631_96, 0.013_339_732, 0.014_070_112,
+    0.014_823_304, 0.015_599_503, 0.016_398_91, 0.017_221_717, 0.018_068_116, 0.018_938_296, 0.019_832_443, 0.020_750_746,
+    0.021_693_384, 0.022_660_539, 0.023_652_392, 0.024_669_116, 0.025_710_888, 0.026_777_884, 0.027_870_271, 0.028_988_222,
+    0.030_131_903, 0.031_301_484, 0.032_497_123, 0.033_718_99, 0.034_967_244, 0.036_242_045, 0.037_543_554, 0.038_871_93,
+    0.040_227_32, 0.041_609_89, 0.043_019_786, 0.044_457_164, 0.045_922_175, 0.047_414_966, 0.048_935_69, 0.050_484_486,
+    0.052_061_51, 0.053_666_9, 0.055_300_802, 0.056_963_36, 0.058_654_718, 0.060_375_012, 0.062_124_386, 0.063_902_974,
+    0.065_710_92, 0.067_548_36, 0.069_415_41, 0.071_312_24, 0.073_238_96, 0.075_195_71, 0.077_182_62, 0.079_199_82,
+    0.081_247_45, 0.083_325_624, 0.085_434_49, 0.087_574_16, 0.089_744_77, 0.091_946_445, 0.094_179_3, 0.096_443_48,
+    0.098_739_095, 0.101_066_28, 0.103_425_14, 0.105_815_805, 0.108_238_41, 0.110_693_05, 0.113_179_87, 0.115_698_97,
+    0.118_250_49, 0.120_834_52, 0.123_451_2, 0.126_100_64, 0.128_782_96, 0.131_498_26, 0.134_246_68, 0.137_028_3,
+    0.139_843_29, 0.142_691_69, 0.145_573_66, 0.148_489_31, 0.151_438_74, 0.154_422_06, 0.157_439_4, 0.160_490_84,
+    0.163_576_5, 0.166_696_5, 0.169_850_95, 0.173_039_93, 0.176_263_57, 0.179_521_98, 0.182_815_25, 0.186_143_5,
+    0.189_506_84, 0.192_905_35, 0.196_339_16, 0.199_808_36, 0.203_313_05, 0.206_853_35, 0.210_429_34, 0.214_041_14,
+    0.217_688_86, 0.221_372_57, 0.225_092_4, 0.228_848_43, 0.232_640_77, 0.236_469_52, 0.240_334_78, 0.244_236_65,
+    0.248_175_22, 0.252_150_6, 0.256_162_85, 0.260_212_12, 0.264_298_5, 0.268_422_04, 0.272_582_9, 0.276_781_1,
+    0.281_016_83, 0.285_290_1, 0.289_601_03, 0.293_949_75, 0.298_336_3, 0.302_760_8, 0.307_223_38, 0.311_724_07,
+    0.316_262_96, 0.320_840_2, 0.325_455_84, 0.33011, 0.334_802_75, 0.339_534_2, 0.344_304_38, 0.349_113_46,
+    0.353_961_5, 0.358_848_57, 0.363_774_8, 0.368_740_23, 0.373_745, 0.378_789_16, 0.383_872_78, 0.388_996,
+    0.394_158_9, 0.399_361_55, 0.404_604_02, 0.409_886_42, 0.415_208_85, 0.420_571_36, 0.425_974_07, 0.431_417_05,
+    0.436_900_38, 0.442_424_12, 0.447_988_42, 0.453_593_34, 0.459_238_92, 0.464_925_32, 0.470_652_55, 0.476_420_73,
+    0.482_229_95, 0.488_080_26, 0.493_971_77, 0.499_904_57, 0.505_878_75, 0.511_894_35, 0.517_951_4, 0.524_050_2,
+    0.530_190_6, 0.536_372_7, 0.542_596_76, 0.548_862_7, 0.555_170_66, 0.561_520_7, 0.567_912_94, 0.574_347_4,
+    0.580_824_14, 0.587_343_34, 0.593_905_03, 0.600_509_3, 0.607_156_16, 0.613_845_77, 0.620_578_17, 0.627_353_4,
+    0.634_171_66, 0.641_032_93, 0.647_937_3, 0.654_884_9, 0.661_875_67, 0.668_909_85, 0.675_987_4, 0.683_108_45,
+    0.690_273_1, 0.697_481_4, 0.704_733_4, 0.712_029_16, 0.719_368_9, 0.726_752_46, 0.734_180_1, 0.741_651_83,
+    0.749_167_74, 0.756_727_9, 0.764_332_3, 0.771_981_2, 0.779_674_5, 0.787_412_35, 0.795_194_8, 0.803_021_9,
+    0.810_893_83, 0.818_810_6, 0.826_772_2, 0.834_778_85, 0.842_830_54, 0.850_927_3, 0.859_069_3, 0.867_256_5,
+    0.875_489_1, 0.883_767_1, 0.892_090_56, 0.900_459_6, 0.908_874_2, 0.917_334_56, 0.925_840_7, 0.934_392_6,
+    0.942_990_4, 0.951_634_2, 0.960_324_05, 0.969_06, 0.977_842_15, 0.986_670_55, 0.995_545_27,
+];
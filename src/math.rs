@@ -0,0 +1,28 @@
+//! The `f32` functions that `core` doesn't provide, backed by `std` when it's
+//! available and by [`libm`](::libm) otherwise.
+
+macro_rules! forward {
+    ($(fn $name:ident($($arg:ident),+) => $libm:ident;)+) => {$(
+        #[inline]
+        pub(crate) fn $name($($arg: f32),+) -> f32 {
+            #[cfg(feature = "std")]
+            {
+                f32::$name($($arg),+)
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                ::libm::$libm($($arg),+)
+            }
+        }
+    )+};
+}
+
+forward! {
+    fn atan2(y, x) => atan2f;
+    fn cbrt(x) => cbrtf;
+    fn cos(x) => cosf;
+    fn exp(x) => expf;
+    fn powf(x, n) => powf;
+    fn sin(x) => sinf;
+    fn sqrt(x) => sqrtf;
+}
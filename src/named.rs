@@ -0,0 +1,745 @@
+//! Every named color of [CSS Color Module Level 4] as a [`HexColor`] constant.
+//!
+//! The constants are named after their CSS keyword in uppercase, e.g.,
+//! `rebeccapurple` is [`REBECCAPURPLE`]. Keep in mind that a few of them
+//! disagree with the similarly named constants on [`HexColor`] itself: CSS
+//! `green` is `#008000`, whereas [`HexColor::GREEN`] is `#00FF00`, which CSS
+//! calls `lime`.
+//!
+//! To look a color up by its name at runtime, use [`HexColor::from_name`], and
+//! to go the other way, use [`HexColor::name`], or [`HexColor::nearest_name`]
+//! for colors without a name of their own.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_color::{named, HexColor};
+//!
+//! assert_eq!(named::REBECCAPURPLE, HexColor::rgb(102, 51, 153));
+//! assert_eq!(named::LIME, HexColor::GREEN);
+//! assert_ne!(named::GREEN, HexColor::GREEN);
+//! ```
+//!
+//! [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/#named-colors
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod x11;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod xkcd;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::delta_e;
+use crate::HexColor;
+
+impl HexColor {
+    /// Looks up a [CSS named color] by its keyword, ignoring ASCII case.
+    ///
+    /// Every keyword in the [`named`](crate::named) module is recognized,
+    /// including `transparent`. The lookup is a single probe into a perfect
+    /// hash table that's built at compile time, so it doesn't get slower as
+    /// the list of names grows.
+    ///
+    /// [CSS named color]: https://www.w3.org/TR/css-color-4/#named-colors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{named, HexColor};
+    ///
+    /// assert_eq!(HexColor::from_name("rebeccapurple"), Some(named::REBECCAPURPLE));
+    /// assert_eq!(HexColor::from_name("DodgerBlue"), Some(HexColor::rgb(30, 144, 255)));
+    /// assert_eq!(HexColor::from_name("transparent"), Some(HexColor::CLEAR));
+    /// assert_eq!(HexColor::from_name("notacolor"), None);
+    /// ```
+    #[must_use]
+    pub const fn from_name(name: &str) -> Option<HexColor> {
+        let name = name.as_bytes();
+        let seed = INDEX.seeds[reduce(hash(name, 0), BUCKETS)];
+        let slot = INDEX.slots[reduce(hash(name, seed), SLOTS)];
+        if slot == EMPTY {
+            return None;
+        }
+        let (candidate, color) = NAMES[slot as usize];
+        if eq_ignore_ascii_case(candidate.as_bytes(), name) {
+            Some(color)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the CSS keyword of the color, if it has one.
+    ///
+    /// Only exact matches count, so the color must have the exact RGB
+    /// components of a [named color](crate::named) and be fully opaque, or be
+    /// [`HexColor::CLEAR`], which is `transparent`. Where CSS has several
+    /// names for the same color, such as `aqua` and `cyan`, or `gray` and
+    /// `grey`, the first one in alphabetical order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{named, HexColor};
+    ///
+    /// assert_eq!(named::REBECCAPURPLE.name(), Some("rebeccapurple"));
+    /// assert_eq!(HexColor::CYAN.name(), Some("aqua"));
+    /// assert_eq!(HexColor::GRAY.name(), Some("gray"));
+    /// assert_eq!(HexColor::CLEAR.name(), Some("transparent"));
+    ///
+    /// assert_eq!(HexColor::rgb(102, 51, 154).name(), None);
+    /// assert_eq!(named::REBECCAPURPLE.with_a(128).name(), None);
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> Option<&'static str> {
+        let mut i = 0;
+        while i < NAMES.len() {
+            let (name, color) = NAMES[i];
+            if color.to_u32() == self.to_u32() {
+                return Some(name);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the name in `set` that's perceptually closest to the color,
+    /// along with how far away it is.
+    ///
+    /// The distance is the Euclidean distance between the two colors in
+    /// [Oklab], i.e., [`Metric::Oklab`](crate::Metric::Oklab), where `0.0` is
+    /// an exact match, and about `0.02` is the smallest difference that's
+    /// noticeable. The alpha component is ignored,
+    /// so `transparent` is never returned. Ties go to the name listed first
+    /// in the set.
+    ///
+    /// [Oklab]: https://bottosson.github.io/posts/oklab/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, NameSet};
+    ///
+    /// let (name, distance) = HexColor::rgb(102, 51, 154).nearest_name(NameSet::Css);
+    /// assert_eq!(name, "rebeccapurple");
+    /// assert!(distance < 0.01);
+    ///
+    /// let (name, distance) = HexColor::rgb(190, 190, 190).nearest_name(NameSet::X11);
+    /// assert_eq!(name, "gray");
+    /// assert_eq!(distance, 0.0);
+    ///
+    /// let (name, _) = HexColor::rgb(229, 0, 0).nearest_name(NameSet::Xkcd);
+    /// assert_eq!(name, "red");
+    /// ```
+    #[must_use]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn nearest_name(self, set: NameSet) -> (&'static str, f32) {
+        let names: &[(&str, HexColor)] = match set {
+            NameSet::Css => &NAMES,
+            NameSet::X11 => &x11::COLORS,
+            NameSet::Xkcd => &xkcd::COLORS,
+        };
+        let target = self.to_oklab();
+        let mut nearest = ("", f32::INFINITY);
+        for &(name, color) in names.iter().filter(|(_, color)| color.a == u8::MAX) {
+            let distance = delta_e::oklab(target, color.to_oklab());
+            if distance < nearest.1 {
+                nearest = (name, distance);
+            }
+        }
+        nearest
+    }
+}
+
+/// A list of color names to search with [`HexColor::nearest_name`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum NameSet {
+    /// The named colors of CSS, i.e., the constants of this module, e.g.,
+    /// `rebeccapurple`.
+    #[default]
+    Css,
+    /// The 658 color names of the X Window System's `rgb.txt`, e.g.,
+    /// `DarkSlateGray4`. Where CSS and X11 disagree, such as on `gray` and
+    /// `green`, the X11 values are used.
+    X11,
+    /// The 949 color names of the [xkcd color survey], e.g., `dusty rose`.
+    ///
+    /// [xkcd color survey]: https://xkcd.com/color/rgb/
+    Xkcd,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+
+/// CSS `aliceblue`. RGBA is `(240, 248, 255, 255)`.
+pub const ALICEBLUE: HexColor = HexColor::rgb(240, 248, 255);
+/// CSS `antiquewhite`. RGBA is `(250, 235, 215, 255)`.
+pub const ANTIQUEWHITE: HexColor = HexColor::rgb(250, 235, 215);
+/// CSS `aqua`. RGBA is `(0, 255, 255, 255)`.
+pub const AQUA: HexColor = HexColor::rgb(0, 255, 255);
+/// CSS `aquamarine`. RGBA is `(127, 255, 212, 255)`.
+pub const AQUAMARINE: HexColor = HexColor::rgb(127, 255, 212);
+/// CSS `azure`. RGBA is `(240, 255, 255, 255)`.
+pub const AZURE: HexColor = HexColor::rgb(240, 255, 255);
+/// CSS `beige`. RGBA is `(245, 245, 220, 255)`.
+pub const BEIGE: HexColor = HexColor::rgb(245, 245, 220);
+/// CSS `bisque`. RGBA is `(255, 228, 196, 255)`.
+pub const BISQUE: HexColor = HexColor::rgb(255, 228, 196);
+/// CSS `black`. RGBA is `(0, 0, 0, 255)`.
+pub const BLACK: HexColor = HexColor::rgb(0, 0, 0);
+/// CSS `blanchedalmond`. RGBA is `(255, 235, 205, 255)`.
+pub const BLANCHEDALMOND: HexColor = HexColor::rgb(255, 235, 205);
+/// CSS `blue`. RGBA is `(0, 0, 255, 255)`.
+pub const BLUE: HexColor = HexColor::rgb(0, 0, 255);
+/// CSS `blueviolet`. RGBA is `(138, 43, 226, 255)`.
+pub const BLUEVIOLET: HexColor = HexColor::rgb(138, 43, 226);
+/// CSS `brown`. RGBA is `(165, 42, 42, 255)`.
+pub const BROWN: HexColor = HexColor::rgb(165, 42, 42);
+/// CSS `burlywood`. RGBA is `(222, 184, 135, 255)`.
+pub const BURLYWOOD: HexColor = HexColor::rgb(222, 184, 135);
+/// CSS `cadetblue`. RGBA is `(95, 158, 160, 255)`.
+pub const CADETBLUE: HexColor = HexColor::rgb(95, 158, 160);
+/// CSS `chartreuse`. RGBA is `(127, 255, 0, 255)`.
+pub const CHARTREUSE: HexColor = HexColor::rgb(127, 255, 0);
+/// CSS `chocolate`. RGBA is `(210, 105, 30, 255)`.
+pub const CHOCOLATE: HexColor = HexColor::rgb(210, 105, 30);
+/// CSS `coral`. RGBA is `(255, 127, 80, 255)`.
+pub const CORAL: HexColor = HexColor::rgb(255, 127, 80);
+/// CSS `cornflowerblue`. RGBA is `(100, 149, 237, 255)`.
+pub const CORNFLOWERBLUE: HexColor = HexColor::rgb(100, 149, 237);
+/// CSS `cornsilk`. RGBA is `(255, 248, 220, 255)`.
+pub const CORNSILK: HexColor = HexColor::rgb(255, 248, 220);
+/// CSS `crimson`. RGBA is `(220, 20, 60, 255)`.
+pub const CRIMSON: HexColor = HexColor::rgb(220, 20, 60);
+/// CSS `cyan`. RGBA is `(0, 255, 255, 255)`.
+pub const CYAN: HexColor = HexColor::rgb(0, 255, 255);
+/// CSS `darkblue`. RGBA is `(0, 0, 139, 255)`.
+pub const DARKBLUE: HexColor = HexColor::rgb(0, 0, 139);
+/// CSS `darkcyan`. RGBA is `(0, 139, 139, 255)`.
+pub const DARKCYAN: HexColor = HexColor::rgb(0, 139, 139);
+/// CSS `darkgoldenrod`. RGBA is `(184, 134, 11, 255)`.
+pub const DARKGOLDENROD: HexColor = HexColor::rgb(184, 134, 11);
+/// CSS `darkgray`. RGBA is `(169, 169, 169, 255)`.
+pub const DARKGRAY: HexColor = HexColor::rgb(169, 169, 169);
+/// CSS `darkgreen`. RGBA is `(0, 100, 0, 255)`.
+pub const DARKGREEN: HexColor = HexColor::rgb(0, 100, 0);
+/// CSS `darkgrey`. RGBA is `(169, 169, 169, 255)`.
+pub const DARKGREY: HexColor = HexColor::rgb(169, 169, 169);
+/// CSS `darkkhaki`. RGBA is `(189, 183, 107, 255)`.
+pub const DARKKHAKI: HexColor = HexColor::rgb(189, 183, 107);
+/// CSS `darkmagenta`. RGBA is `(139, 0, 139, 255)`.
+pub const DARKMAGENTA: HexColor = HexColor::rgb(139, 0, 139);
+/// CSS `darkolivegreen`. RGBA is `(85, 107, 47, 255)`.
+pub const DARKOLIVEGREEN: HexColor = HexColor::rgb(85, 107, 47);
+/// CSS `darkorange`. RGBA is `(255, 140, 0, 255)`.
+pub const DARKORANGE: HexColor = HexColor::rgb(255, 140, 0);
+/// CSS `darkorchid`. RGBA is `(153, 50, 204, 255)`.
+pub const DARKORCHID: HexColor = HexColor::rgb(153, 50, 204);
+/// CSS `darkred`. RGBA is `(139, 0, 0, 255)`.
+pub const DARKRED: HexColor = HexColor::rgb(139, 0, 0);
+/// CSS `darksalmon`. RGBA is `(233, 150, 122, 255)`.
+pub const DARKSALMON: HexColor = HexColor::rgb(233, 150, 122);
+/// CSS `darkseagreen`. RGBA is `(143, 188, 143, 255)`.
+pub const DARKSEAGREEN: HexColor = HexColor::rgb(143, 188, 143);
+/// CSS `darkslateblue`. RGBA is `(72, 61, 139, 255)`.
+pub const DARKSLATEBLUE: HexColor = HexColor::rgb(72, 61, 139);
+/// CSS `darkslategray`. RGBA is `(47, 79, 79, 255)`.
+pub const DARKSLATEGRAY: HexColor = HexColor::rgb(47, 79, 79);
+/// CSS `darkslategrey`. RGBA is `(47, 79, 79, 255)`.
+pub const DARKSLATEGREY: HexColor = HexColor::rgb(47, 79, 79);
+/// CSS `darkturquoise`. RGBA is `(0, 206, 209, 255)`.
+pub const DARKTURQUOISE: HexColor = HexColor::rgb(0, 206, 209);
+/// CSS `darkviolet`. RGBA is `(148, 0, 211, 255)`.
+pub const DARKVIOLET: HexColor = HexColor::rgb(148, 0, 211);
+/// CSS `deeppink`. RGBA is `(255, 20, 147, 255)`.
+pub const DEEPPINK: HexColor = HexColor::rgb(255, 20, 147);
+/// CSS `deepskyblue`. RGBA is `(0, 191, 255, 255)`.
+pub const DEEPSKYBLUE: HexColor = HexColor::rgb(0, 191, 255);
+/// CSS `dimgray`. RGBA is `(105, 105, 105, 255)`.
+pub const DIMGRAY: HexColor = HexColor::rgb(105, 105, 105);
+/// CSS `dimgrey`. RGBA is `(105, 105, 105, 255)`.
+pub const DIMGREY: HexColor = HexColor::rgb(105, 105, 105);
+/// CSS `dodgerblue`. RGBA is `(30, 144, 255, 255)`.
+pub const DODGERBLUE: HexColor = HexColor::rgb(30, 144, 255);
+/// CSS `firebrick`. RGBA is `(178, 34, 34, 255)`.
+pub const FIREBRICK: HexColor = HexColor::rgb(178, 34, 34);
+/// CSS `floralwhite`. RGBA is `(255, 250, 240, 255)`.
+pub const FLORALWHITE: HexColor = HexColor::rgb(255, 250, 240);
+/// CSS `forestgreen`. RGBA is `(34, 139, 34, 255)`.
+pub const FORESTGREEN: HexColor = HexColor::rgb(34, 139, 34);
+/// CSS `fuchsia`. RGBA is `(255, 0, 255, 255)`.
+pub const FUCHSIA: HexColor = HexColor::rgb(255, 0, 255);
+/// CSS `gainsboro`. RGBA is `(220, 220, 220, 255)`.
+pub const GAINSBORO: HexColor = HexColor::rgb(220, 220, 220);
+/// CSS `ghostwhite`. RGBA is `(248, 248, 255, 255)`.
+pub const GHOSTWHITE: HexColor = HexColor::rgb(248, 248, 255);
+/// CSS `gold`. RGBA is `(255, 215, 0, 255)`.
+pub const GOLD: HexColor = HexColor::rgb(255, 215, 0);
+/// CSS `goldenrod`. RGBA is `(218, 165, 32, 255)`.
+pub const GOLDENROD: HexColor = HexColor::rgb(218, 165, 32);
+/// CSS `gray`. RGBA is `(128, 128, 128, 255)`.
+pub const GRAY: HexColor = HexColor::rgb(128, 128, 128);
+/// CSS `green`. RGBA is `(0, 128, 0, 255)`.
+pub const GREEN: HexColor = HexColor::rgb(0, 128, 0);
+/// CSS `greenyellow`. RGBA is `(173, 255, 47, 255)`.
+pub const GREENYELLOW: HexColor = HexColor::rgb(173, 255, 47);
+/// CSS `grey`. RGBA is `(128, 128, 128, 255)`.
+pub const GREY: HexColor = HexColor::rgb(128, 128, 128);
+/// CSS `honeydew`. RGBA is `(240, 255, 240, 255)`.
+pub const HONEYDEW: HexColor = HexColor::rgb(240, 255, 240);
+/// CSS `hotpink`. RGBA is `(255, 105, 180, 255)`.
+pub const HOTPINK: HexColor = HexColor::rgb(255, 105, 180);
+/// CSS `indianred`. RGBA is `(205, 92, 92, 255)`.
+pub const INDIANRED: HexColor = HexColor::rgb(205, 92, 92);
+/// CSS `indigo`. RGBA is `(75, 0, 130, 255)`.
+pub const INDIGO: HexColor = HexColor::rgb(75, 0, 130);
+/// CSS `ivory`. RGBA is `(255, 255, 240, 255)`.
+pub const IVORY: HexColor = HexColor::rgb(255, 255, 240);
+/// CSS `khaki`. RGBA is `(240, 230, 140, 255)`.
+pub const KHAKI: HexColor = HexColor::rgb(240, 230, 140);
+/// CSS `lavender`. RGBA is `(230, 230, 250, 255)`.
+pub const LAVENDER: HexColor = HexColor::rgb(230, 230, 250);
+/// CSS `lavenderblush`. RGBA is `(255, 240, 245, 255)`.
+pub const LAVENDERBLUSH: HexColor = HexColor::rgb(255, 240, 245);
+/// CSS `lawngreen`. RGBA is `(124, 252, 0, 255)`.
+pub const LAWNGREEN: HexColor = HexColor::rgb(124, 252, 0);
+/// CSS `lemonchiffon`. RGBA is `(255, 250, 205, 255)`.
+pub const LEMONCHIFFON: HexColor = HexColor::rgb(255, 250, 205);
+/// CSS `lightblue`. RGBA is `(173, 216, 230, 255)`.
+pub const LIGHTBLUE: HexColor = HexColor::rgb(173, 216, 230);
+/// CSS `lightcoral`. RGBA is `(240, 128, 128, 255)`.
+pub const LIGHTCORAL: HexColor = HexColor::rgb(240, 128, 128);
+/// CSS `lightcyan`. RGBA is `(224, 255, 255, 255)`.
+pub const LIGHTCYAN: HexColor = HexColor::rgb(224, 255, 255);
+/// CSS `lightgoldenrodyellow`. RGBA is `(250, 250, 210, 255)`.
+pub const LIGHTGOLDENRODYELLOW: HexColor = HexColor::rgb(250, 250, 210);
+/// CSS `lightgray`. RGBA is `(211, 211, 211, 255)`.
+pub const LIGHTGRAY: HexColor = HexColor::rgb(211, 211, 211);
+/// CSS `lightgreen`. RGBA is `(144, 238, 144, 255)`.
+pub const LIGHTGREEN: HexColor = HexColor::rgb(144, 238, 144);
+/// CSS `lightgrey`. RGBA is `(211, 211, 211, 255)`.
+pub const LIGHTGREY: HexColor = HexColor::rgb(211, 211, 211);
+/// CSS `lightpink`. RGBA is `(255, 182, 193, 255)`.
+pub const LIGHTPINK: HexColor = HexColor::rgb(255, 182, 193);
+/// CSS `lightsalmon`. RGBA is `(255, 160, 122, 255)`.
+pub const LIGHTSALMON: HexColor = HexColor::rgb(255, 160, 122);
+/// CSS `lightseagreen`. RGBA is `(32, 178, 170, 255)`.
+pub const LIGHTSEAGREEN: HexColor = HexColor::rgb(32, 178, 170);
+/// CSS `lightskyblue`. RGBA is `(135, 206, 250, 255)`.
+pub const LIGHTSKYBLUE: HexColor = HexColor::rgb(135, 206, 250);
+/// CSS `lightslategray`. RGBA is `(119, 136, 153, 255)`.
+pub const LIGHTSLATEGRAY: HexColor = HexColor::rgb(119, 136, 153);
+/// CSS `lightslategrey`. RGBA is `(119, 136, 153, 255)`.
+pub const LIGHTSLATEGREY: HexColor = HexColor::rgb(119, 136, 153);
+/// CSS `lightsteelblue`. RGBA is `(176, 196, 222, 255)`.
+pub const LIGHTSTEELBLUE: HexColor = HexColor::rgb(176, 196, 222);
+/// CSS `lightyellow`. RGBA is `(255, 255, 224, 255)`.
+pub const LIGHTYELLOW: HexColor = HexColor::rgb(255, 255, 224);
+/// CSS `lime`. RGBA is `(0, 255, 0, 255)`.
+pub const LIME: HexColor = HexColor::rgb(0, 255, 0);
+/// CSS `limegreen`. RGBA is `(50, 205, 50, 255)`.
+pub const LIMEGREEN: HexColor = HexColor::rgb(50, 205, 50);
+/// CSS `linen`. RGBA is `(250, 240, 230, 255)`.
+pub const LINEN: HexColor = HexColor::rgb(250, 240, 230);
+/// CSS `magenta`. RGBA is `(255, 0, 255, 255)`.
+pub const MAGENTA: HexColor = HexColor::rgb(255, 0, 255);
+/// CSS `maroon`. RGBA is `(128, 0, 0, 255)`.
+pub const MAROON: HexColor = HexColor::rgb(128, 0, 0);
+/// CSS `mediumaquamarine`. RGBA is `(102, 205, 170, 255)`.
+pub const MEDIUMAQUAMARINE: HexColor = HexColor::rgb(102, 205, 170);
+/// CSS `mediumblue`. RGBA is `(0, 0, 205, 255)`.
+pub const MEDIUMBLUE: HexColor = HexColor::rgb(0, 0, 205);
+/// CSS `mediumorchid`. RGBA is `(186, 85, 211, 255)`.
+pub const MEDIUMORCHID: HexColor = HexColor::rgb(186, 85, 211);
+/// CSS `mediumpurple`. RGBA is `(147, 112, 219, 255)`.
+pub const MEDIUMPURPLE: HexColor = HexColor::rgb(147, 112, 219);
+/// CSS `mediumseagreen`. RGBA is `(60, 179, 113, 255)`.
+pub const MEDIUMSEAGREEN: HexColor = HexColor::rgb(60, 179, 113);
+/// CSS `mediumslateblue`. RGBA is `(123, 104, 238, 255)`.
+pub const MEDIUMSLATEBLUE: HexColor = HexColor::rgb(123, 104, 238);
+/// CSS `mediumspringgreen`. RGBA is `(0, 250, 154, 255)`.
+pub const MEDIUMSPRINGGREEN: HexColor = HexColor::rgb(0, 250, 154);
+/// CSS `mediumturquoise`. RGBA is `(72, 209, 204, 255)`.
+pub const MEDIUMTURQUOISE: HexColor = HexColor::rgb(72, 209, 204);
+/// CSS `mediumvioletred`. RGBA is `(199, 21, 133, 255)`.
+pub const MEDIUMVIOLETRED: HexColor = HexColor::rgb(199, 21, 133);
+/// CSS `midnightblue`. RGBA is `(25, 25, 112, 255)`.
+pub const MIDNIGHTBLUE: HexColor = HexColor::rgb(25, 25, 112);
+/// CSS `mintcream`. RGBA is `(245, 255, 250, 255)`.
+pub const MINTCREAM: HexColor = HexColor::rgb(245, 255, 250);
+/// CSS `mistyrose`. RGBA is `(255, 228, 225, 255)`.
+pub const MISTYROSE: HexColor = HexColor::rgb(255, 228, 225);
+/// CSS `moccasin`. RGBA is `(255, 228, 181, 255)`.
+pub const MOCCASIN: HexColor = HexColor::rgb(255, 228, 181);
+/// CSS `navajowhite`. RGBA is `(255, 222, 173, 255)`.
+pub const NAVAJOWHITE: HexColor = HexColor::rgb(255, 222, 173);
+/// CSS `navy`. RGBA is `(0, 0, 128, 255)`.
+pub const NAVY: HexColor = HexColor::rgb(0, 0, 128);
+/// CSS `oldlace`. RGBA is `(253, 245, 230, 255)`.
+pub const OLDLACE: HexColor = HexColor::rgb(253, 245, 230);
+/// CSS `olive`. RGBA is `(128, 128, 0, 255)`.
+pub const OLIVE: HexColor = HexColor::rgb(128, 128, 0);
+/// CSS `olivedrab`. RGBA is `(107, 142, 35, 255)`.
+pub const OLIVEDRAB: HexColor = HexColor::rgb(107, 142, 35);
+/// CSS `orange`. RGBA is `(255, 165, 0, 255)`.
+pub const ORANGE: HexColor = HexColor::rgb(255, 165, 0);
+/// CSS `orangered`. RGBA is `(255, 69, 0, 255)`.
+pub const ORANGERED: HexColor = HexColor::rgb(255, 69, 0);
+/// CSS `orchid`. RGBA is `(218, 112, 214, 255)`.
+pub const ORCHID: HexColor = HexColor::rgb(218, 112, 214);
+/// CSS `palegoldenrod`. RGBA is `(238, 232, 170, 255)`.
+pub const PALEGOLDENROD: HexColor = HexColor::rgb(238, 232, 170);
+/// CSS `palegreen`. RGBA is `(152, 251, 152, 255)`.
+pub const PALEGREEN: HexColor = HexColor::rgb(152, 251, 152);
+/// CSS `paleturquoise`. RGBA is `(175, 238, 238, 255)`.
+pub const PALETURQUOISE: HexColor = HexColor::rgb(175, 238, 238);
+/// CSS `palevioletred`. RGBA is `(219, 112, 147, 255)`.
+pub const PALEVIOLETRED: HexColor = HexColor::rgb(219, 112, 147);
+/// CSS `papayawhip`. RGBA is `(255, 239, 213, 255)`.
+pub const PAPAYAWHIP: HexColor = HexColor::rgb(255, 239, 213);
+/// CSS `peachpuff`. RGBA is `(255, 218, 185, 255)`.
+pub const PEACHPUFF: HexColor = HexColor::rgb(255, 218, 185);
+/// CSS `peru`. RGBA is `(205, 133, 63, 255)`.
+pub const PERU: HexColor = HexColor::rgb(205, 133, 63);
+/// CSS `pink`. RGBA is `(255, 192, 203, 255)`.
+pub const PINK: HexColor = HexColor::rgb(255, 192, 203);
+/// CSS `plum`. RGBA is `(221, 160, 221, 255)`.
+pub const PLUM: HexColor = HexColor::rgb(221, 160, 221);
+/// CSS `powderblue`. RGBA is `(176, 224, 230, 255)`.
+pub const POWDERBLUE: HexColor = HexColor::rgb(176, 224, 230);
+/// CSS `purple`. RGBA is `(128, 0, 128, 255)`.
+pub const PURPLE: HexColor = HexColor::rgb(128, 0, 128);
+/// CSS `rebeccapurple`. RGBA is `(102, 51, 153, 255)`.
+pub const REBECCAPURPLE: HexColor = HexColor::rgb(102, 51, 153);
+/// CSS `red`. RGBA is `(255, 0, 0, 255)`.
+pub const RED: HexColor = HexColor::rgb(255, 0, 0);
+/// CSS `rosybrown`. RGBA is `(188, 143, 143, 255)`.
+pub const ROSYBROWN: HexColor = HexColor::rgb(188, 143, 143);
+/// CSS `royalblue`. RGBA is `(65, 105, 225, 255)`.
+pub const ROYALBLUE: HexColor = HexColor::rgb(65, 105, 225);
+/// CSS `saddlebrown`. RGBA is `(139, 69, 19, 255)`.
+pub const SADDLEBROWN: HexColor = HexColor::rgb(139, 69, 19);
+/// CSS `salmon`. RGBA is `(250, 128, 114, 255)`.
+pub const SALMON: HexColor = HexColor::rgb(250, 128, 114);
+/// CSS `sandybrown`. RGBA is `(244, 164, 96, 255)`.
+pub const SANDYBROWN: HexColor = HexColor::rgb(244, 164, 96);
+/// CSS `seagreen`. RGBA is `(46, 139, 87, 255)`.
+pub const SEAGREEN: HexColor = HexColor::rgb(46, 139, 87);
+/// CSS `seashell`. RGBA is `(255, 245, 238, 255)`.
+pub const SEASHELL: HexColor = HexColor::rgb(255, 245, 238);
+/// CSS `sienna`. RGBA is `(160, 82, 45, 255)`.
+pub const SIENNA: HexColor = HexColor::rgb(160, 82, 45);
+/// CSS `silver`. RGBA is `(192, 192, 192, 255)`.
+pub const SILVER: HexColor = HexColor::rgb(192, 192, 192);
+/// CSS `skyblue`. RGBA is `(135, 206, 235, 255)`.
+pub const SKYBLUE: HexColor = HexColor::rgb(135, 206, 235);
+/// CSS `slateblue`. RGBA is `(106, 90, 205, 255)`.
+pub const SLATEBLUE: HexColor = HexColor::rgb(106, 90, 205);
+/// CSS `slategray`. RGBA is `(112, 128, 144, 255)`.
+pub const SLATEGRAY: HexColor = HexColor::rgb(112, 128, 144);
+/// CSS `slategrey`. RGBA is `(112, 128, 144, 255)`.
+pub const SLATEGREY: HexColor = HexColor::rgb(112, 128, 144);
+/// CSS `snow`. RGBA is `(255, 250, 250, 255)`.
+pub const SNOW: HexColor = HexColor::rgb(255, 250, 250);
+/// CSS `springgreen`. RGBA is `(0, 255, 127, 255)`.
+pub const SPRINGGREEN: HexColor = HexColor::rgb(0, 255, 127);
+/// CSS `steelblue`. RGBA is `(70, 130, 180, 255)`.
+pub const STEELBLUE: HexColor = HexColor::rgb(70, 130, 180);
+/// CSS `tan`. RGBA is `(210, 180, 140, 255)`.
+pub const TAN: HexColor = HexColor::rgb(210, 180, 140);
+/// CSS `teal`. RGBA is `(0, 128, 128, 255)`.
+pub const TEAL: HexColor = HexColor::rgb(0, 128, 128);
+/// CSS `thistle`. RGBA is `(216, 191, 216, 255)`.
+pub const THISTLE: HexColor = HexColor::rgb(216, 191, 216);
+/// CSS `tomato`. RGBA is `(255, 99, 71, 255)`.
+pub const TOMATO: HexColor = HexColor::rgb(255, 99, 71);
+/// CSS `transparent`, which is the same as [`HexColor::CLEAR`]. RGBA is
+/// `(0, 0, 0, 0)`.
+pub const TRANSPARENT: HexColor = HexColor::CLEAR;
+/// CSS `turquoise`. RGBA is `(64, 224, 208, 255)`.
+pub const TURQUOISE: HexColor = HexColor::rgb(64, 224, 208);
+/// CSS `violet`. RGBA is `(238, 130, 238, 255)`.
+pub const VIOLET: HexColor = HexColor::rgb(238, 130, 238);
+/// CSS `wheat`. RGBA is `(245, 222, 179, 255)`.
+pub const WHEAT: HexColor = HexColor::rgb(245, 222, 179);
+/// CSS `white`. RGBA is `(255, 255, 255, 255)`.
+pub const WHITE: HexColor = HexColor::rgb(255, 255, 255);
+/// CSS `whitesmoke`. RGBA is `(245, 245, 245, 255)`.
+pub const WHITESMOKE: HexColor = HexColor::rgb(245, 245, 245);
+/// CSS `yellow`. RGBA is `(255, 255, 0, 255)`.
+pub const YELLOW: HexColor = HexColor::rgb(255, 255, 0);
+/// CSS `yellowgreen`. RGBA is `(154, 205, 50, 255)`.
+pub const YELLOWGREEN: HexColor = HexColor::rgb(154, 205, 50);
+
+////////////////////////////////////////////////////////////////////////////////
+// Perfect hashing
+////////////////////////////////////////////////////////////////////////////////
+
+/// Every named color, sorted by name. The order matters for
+/// [`HexColor::name`], which returns the first match.
+const NAMES: [(&str, HexColor); 149] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("transparent", TRANSPARENT),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+// The perfect hash uses the "hash and displace" scheme: every name is first
+// hashed into one of `BUCKETS` buckets, and each bucket gets its own seed for a
+// second hash into one of `SLOTS` slots. The seeds are searched for at compile
+// time, largest buckets first, until every name lands in a slot of its own.
+
+const BUCKETS: usize = 64;
+const SLOTS: usize = 256;
+const EMPTY: u8 = u8::MAX;
+
+struct Index {
+    seeds: [u64; BUCKETS],
+    slots: [u8; SLOTS],
+}
+
+const INDEX: Index = Index::build();
+
+impl Index {
+    #[allow(clippy::cast_possible_truncation)]
+    const fn build() -> Index {
+        let mut buckets = [0; NAMES.len()];
+        let mut sizes = [0; BUCKETS];
+        let mut largest = 0;
+        let mut i = 0;
+        while i < NAMES.len() {
+            let bucket = reduce(hash(NAMES[i].0.as_bytes(), 0), BUCKETS);
+            buckets[i] = bucket;
+            sizes[bucket] += 1;
+            if sizes[bucket] > largest {
+                largest = sizes[bucket];
+            }
+            i += 1;
+        }
+
+        let mut index = Index {
+            seeds: [0; BUCKETS],
+            slots: [EMPTY; SLOTS],
+        };
+        let mut size = largest;
+        while size > 0 {
+            let mut bucket = 0;
+            while bucket < BUCKETS {
+                if sizes[bucket] == size {
+                    index.seeds[bucket] = index.place(&buckets, bucket);
+                }
+                bucket += 1;
+            }
+            size -= 1;
+        }
+        index
+    }
+
+    /// Finds a seed that puts every name in `bucket` into an empty slot, and
+    /// fills those slots.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn place(&mut self, buckets: &[usize; NAMES.len()], bucket: usize) -> u64 {
+        let mut seed = 1;
+        'search: loop {
+            let mut i = 0;
+            while i < NAMES.len() {
+                if buckets[i] == bucket {
+                    let slot = reduce(hash(NAMES[i].0.as_bytes(), seed), SLOTS);
+                    if self.slots[slot] != EMPTY {
+                        // Undo the slots this seed has filled so far:
+                        let mut slot = 0;
+                        while slot < SLOTS {
+                            let filled = self.slots[slot];
+                            if filled != EMPTY && buckets[filled as usize] == bucket {
+                                self.slots[slot] = EMPTY;
+                            }
+                            slot += 1;
+                        }
+                        seed += 1;
+                        continue 'search;
+                    }
+                    self.slots[slot] = i as u8;
+                }
+                i += 1;
+            }
+            return seed;
+        }
+    }
+}
+
+/// FNV-1a over the ASCII lowercase bytes, mixed with a seed.
+const fn hash(bytes: &[u8], seed: u64) -> u64 {
+    let mut hash = 0xCBF2_9CE4_8422_2325 ^ seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i].to_ascii_lowercase() as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01B3);
+        i += 1;
+    }
+    hash ^ (hash >> 32)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn reduce(hash: u64, len: usize) -> usize {
+    (hash % len as u64) as usize
+}
+
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,667 @@
+//! The color names of the X Window System, as listed in its `rgb.txt`.
+//!
+//! Names containing spaces, e.g., `ghost white`, are left out in favor of their
+//! equivalents without, e.g., `GhostWhite`.
+
+use crate::HexColor;
+
+pub(super) static COLORS: [(&str, HexColor); 658] = [
+    ("snow", HexColor::rgb(255, 250, 250)),
+    ("GhostWhite", HexColor::rgb(248, 248, 255)),
+    ("WhiteSmoke", HexColor::rgb(245, 245, 245)),
+    ("gainsboro", HexColor::rgb(220, 220, 220)),
+    ("FloralWhite", HexColor::rgb(255, 250, 240)),
+    ("OldLace", HexColor::rgb(253, 245, 230)),
+    ("linen", HexColor::rgb(250, 240, 230)),
+    ("AntiqueWhite", HexColor::rgb(250, 235, 215)),
+    ("PapayaWhip", HexColor::rgb(255, 239, 213)),
+    ("BlanchedAlmond", HexColor::rgb(255, 235, 205)),
+    ("bisque", HexColor::rgb(255, 228, 196)),
+    ("PeachPuff", HexColor::rgb(255, 218, 185)),
+    ("NavajoWhite", HexColor::rgb(255, 222, 173)),
+    ("moccasin", HexColor::rgb(255, 228, 181)),
+    ("cornsilk", HexColor::rgb(255, 248, 220)),
+    ("ivory", HexColor::rgb(255, 255, 240)),
+    ("LemonChiffon", HexColor::rgb(255, 250, 205)),
+    ("seashell", HexColor::rgb(255, 245, 238)),
+    ("honeydew", HexColor::rgb(240, 255, 240)),
+    ("MintCream", HexColor::rgb(245, 255, 250)),
+    ("azure", HexColor::rgb(240, 255, 255)),
+    ("AliceBlue", HexColor::rgb(240, 248, 255)),
+    ("lavender", HexColor::rgb(230, 230, 250)),
+    ("LavenderBlush", HexColor::rgb(255, 240, 245)),
+    ("MistyRose", HexColor::rgb(255, 228, 225)),
+    ("white", HexColor::rgb(255, 255, 255)),
+    ("black", HexColor::rgb(0, 0, 0)),
+    ("DarkSlateGray", HexColor::rgb(47, 79, 79)),
+    ("DarkSlateGrey", HexColor::rgb(47, 79, 79)),
+    ("DimGray", HexColor::rgb(105, 105, 105)),
+    ("DimGrey", HexColor::rgb(105, 105, 105)),
+    ("SlateGray", HexColor::rgb(112, 128, 144)),
+    ("SlateGrey", HexColor::rgb(112, 128, 144)),
+    ("LightSlateGray", HexColor::rgb(119, 136, 153)),
+    ("LightSlateGrey", HexColor::rgb(119, 136, 153)),
+    ("gray", HexColor::rgb(190, 190, 190)),
+    ("grey", HexColor::rgb(190, 190, 190)),
+    ("LightGrey", HexColor::rgb(211, 211, 211)),
+    ("LightGray", HexColor::rgb(211, 211, 211)),
+    ("MidnightBlue", HexColor::rgb(25, 25, 112)),
+    ("navy", HexColor::rgb(0, 0, 128)),
+    ("NavyBlue", HexColor::rgb(0, 0, 128)),
+    ("CornflowerBlue", HexColor::rgb(100, 149, 237)),
+    ("DarkSlateBlue", HexColor::rgb(72, 61, 139)),
+    ("SlateBlue", HexColor::rgb(106, 90, 205)),
+    ("MediumSlateBlue", HexColor::rgb(123, 104, 238)),
+    ("LightSlateBlue", HexColor::rgb(132, 112, 255)),
+    ("MediumBlue", HexColor::rgb(0, 0, 205)),
+    ("RoyalBlue", HexColor::rgb(65, 105, 225)),
+    ("blue", HexColor::rgb(0, 0, 255)),
+    ("DodgerBlue", HexColor::rgb(30, 144, 255)),
+    ("DeepSkyBlue", HexColor::rgb(0, 191, 255)),
+    ("SkyBlue", HexColor::rgb(135, 206, 235)),
+    ("LightSkyBlue", HexColor::rgb(135, 206, 250)),
+    ("SteelBlue", HexColor::rgb(70, 130, 180)),
+    ("LightSteelBlue", HexColor::rgb(176, 196, 222)),
+    ("LightBlue", HexColor::rgb(173, 216, 230)),
+    ("PowderBlue", HexColor::rgb(176, 224, 230)),
+    ("PaleTurquoise", HexColor::rgb(175, 238, 238)),
+    ("DarkTurquoise", HexColor::rgb(0, 206, 209)),
+    ("MediumTurquoise", HexColor::rgb(72, 209, 204)),
+    ("turquoise", HexColor::rgb(64, 224, 208)),
+    ("cyan", HexColor::rgb(0, 255, 255)),
+    ("LightCyan", HexColor::rgb(224, 255, 255)),
+    ("CadetBlue", HexColor::rgb(95, 158, 160)),
+    ("MediumAquamarine", HexColor::rgb(102, 205, 170)),
+    ("aquamarine", HexColor::rgb(127, 255, 212)),
+    ("DarkGreen", HexColor::rgb(0, 100, 0)),
+    ("DarkOliveGreen", HexColor::rgb(85, 107, 47)),
+    ("DarkSeaGreen", HexColor::rgb(143, 188, 143)),
+    ("SeaGreen", HexColor::rgb(46, 139, 87)),
+    ("MediumSeaGreen", HexColor::rgb(60, 179, 113)),
+    ("LightSeaGreen", HexColor::rgb(32, 178, 170)),
+    ("PaleGreen", HexColor::rgb(152, 251, 152)),
+    ("SpringGreen", HexColor::rgb(0, 255, 127)),
+    ("LawnGreen", HexColor::rgb(124, 252, 0)),
+    ("green", HexColor::rgb(0, 255, 0)),
+    ("chartreuse", HexColor::rgb(127, 255, 0)),
+    ("MediumSpringGreen", HexColor::rgb(0, 250, 154)),
+    ("GreenYellow", HexColor::rgb(173, 255, 47)),
+    ("LimeGreen", HexColor::rgb(50, 205, 50)),
+    ("YellowGreen", HexColor::rgb(154, 205, 50)),
+    ("ForestGreen", HexColor::rgb(34, 139, 34)),
+    ("OliveDrab", HexColor::rgb(107, 142, 35)),
+    ("DarkKhaki", HexColor::rgb(189, 183, 107)),
+    ("khaki", HexColor::rgb(240, 230, 140)),
+    ("PaleGoldenrod", HexColor::rgb(238, 232, 170)),
+    ("LightGoldenrodYellow", HexColor::rgb(250, 250, 210)),
+    ("LightYellow", HexColor::rgb(255, 255, 224)),
+    ("yellow", HexColor::rgb(255, 255, 0)),
+    ("gold", HexColor::rgb(255, 215, 0)),
+    ("LightGoldenrod", HexColor::rgb(238, 221, 130)),
+    ("goldenrod", HexColor::rgb(218, 165, 32)),
+    ("DarkGoldenrod", HexColor::rgb(184, 134, 11)),
+    ("RosyBrown", HexColor::rgb(188, 143, 143)),
+    ("IndianRed", HexColor::rgb(205, 92, 92)),
+    ("SaddleBrown", HexColor::rgb(139, 69, 19)),
+    ("sienna", HexColor::rgb(160, 82, 45)),
+    ("peru", HexColor::rgb(205, 133, 63)),
+    ("burlywood", HexColor::rgb(222, 184, 135)),
+    ("beige", HexColor::rgb(245, 245, 220)),
+    ("wheat", HexColor::rgb(245, 222, 179)),
+    ("SandyBrown", HexColor::rgb(244, 164, 96)),
+    ("tan", HexColor::rgb(210, 180, 140)),
+    ("chocolate", HexColor::rgb(210, 105, 30)),
+    ("firebrick", HexColor::rgb(178, 34, 34)),
+    ("brown", HexColor::rgb(165, 42, 42)),
+    ("DarkSalmon", HexColor::rgb(233, 150, 122)),
+    ("salmon", HexColor::rgb(250, 128, 114)),
+    ("LightSalmon", HexColor::rgb(255, 160, 122)),
+    ("orange", HexColor::rgb(255, 165, 0)),
+    ("DarkOrange", HexColor::rgb(255, 140, 0)),
+    ("coral", HexColor::rgb(255, 127, 80)),
+    ("LightCoral", HexColor::rgb(240, 128, 128)),
+    ("tomato", HexColor::rgb(255, 99, 71)),
+    ("OrangeRed", HexColor::rgb(255, 69, 0)),
+    ("red", HexColor::rgb(255, 0, 0)),
+    ("HotPink", HexColor::rgb(255, 105, 180)),
+    ("DeepPink", HexColor::rgb(255, 20, 147)),
+    ("pink", HexColor::rgb(255, 192, 203)),
+    ("LightPink", HexColor::rgb(255, 182, 193)),
+    ("PaleVioletRed", HexColor::rgb(219, 112, 147)),
+    ("maroon", HexColor::rgb(176, 48, 96)),
+    ("MediumVioletRed", HexColor::rgb(199, 21, 133)),
+    ("VioletRed", HexColor::rgb(208, 32, 144)),
+    ("magenta", HexColor::rgb(255, 0, 255)),
+    ("violet", HexColor::rgb(238, 130, 238)),
+    ("plum", HexColor::rgb(221, 160, 221)),
+    ("orchid", HexColor::rgb(218, 112, 214)),
+    ("MediumOrchid", HexColor::rgb(186, 85, 211)),
+    ("DarkOrchid", HexColor::rgb(153, 50, 204)),
+    ("DarkViolet", HexColor::rgb(148, 0, 211)),
+    ("BlueViolet", HexColor::rgb(138, 43, 226)),
+    ("purple", HexColor::rgb(160, 32, 240)),
+    ("MediumPurple", HexColor::rgb(147, 112, 219)),
+    ("thistle", HexColor::rgb(216, 191, 216)),
+    ("snow1", HexColor::rgb(255, 250, 250)),
+    ("snow2", HexColor::rgb(238, 233, 233)),
+    ("snow3", HexColor::rgb(205, 201, 201)),
+    ("snow4", HexColor::rgb(139, 137, 137)),
+    ("seashell1", HexColor::rgb(255, 245, 238)),
+    ("seashell2", HexColor::rgb(238, 229, 222)),
+    ("seashell3", HexColor::rgb(205, 197, 191)),
+    ("seashell4", HexColor::rgb(139, 134, 130)),
+    ("AntiqueWhite1", HexColor::rgb(255, 239, 219)),
+    ("AntiqueWhite2", HexColor::rgb(238, 223, 204)),
+    ("AntiqueWhite3", HexColor::rgb(205, 192, 176)),
+    ("AntiqueWhite4", HexColor::rgb(139, 131, 120)),
+    ("bisque1", HexColor::rgb(255, 228, 196)),
+    ("bisque2", HexColor::rgb(238, 213, 183)),
+    ("bisque3", HexColor::rgb(205, 183, 158)),
+    ("bisque4", HexColor::rgb(139, 125, 107)),
+    ("PeachPuff1", HexColor::rgb(255, 218, 185)),
+    ("PeachPuff2", HexColor::rgb(238, 203, 173)),
+    ("PeachPuff3", HexColor::rgb(205, 175, 149)),
+    ("PeachPuff4", HexColor::rgb(139, 119, 101)),
+    ("NavajoWhite1", HexColor::rgb(255, 222, 173)),
+    ("NavajoWhite2", HexColor::rgb(238, 207, 161)),
+    ("NavajoWhite3", HexColor::rgb(205, 179, 139)),
+    ("NavajoWhite4", HexColor::rgb(139, 121, 94)),
+    ("LemonChiffon1", HexColor::rgb(255, 250, 205)),
+    ("LemonChiffon2", HexColor::rgb(238, 233, 191)),
+    ("LemonChiffon3", HexColor::rgb(205, 201, 165)),
+    ("LemonChiffon4", HexColor::rgb(139, 137, 112)),
+    ("cornsilk1", HexColor::rgb(255, 248, 220)),
+    ("cornsilk2", HexColor::rgb(238, 232, 205)),
+    ("cornsilk3", HexColor::rgb(205, 200, 177)),
+    ("cornsilk4", HexColor::rgb(139, 136, 120)),
+    ("ivory1", HexColor::rgb(255, 255, 240)),
+    ("ivory2", HexColor::rgb(238, 238, 224)),
+    ("ivory3", HexColor::rgb(205, 205, 193)),
+    ("ivory4", HexColor::rgb(139, 139, 131)),
+    ("honeydew1", HexColor::rgb(240, 255, 240)),
+    ("honeydew2", HexColor::rgb(224, 238, 224)),
+    ("honeydew3", HexColor::rgb(193, 205, 193)),
+    ("honeydew4", HexColor::rgb(131, 139, 131)),
+    ("LavenderBlush1", HexColor::rgb(255, 240, 245)),
+    ("LavenderBlush2", HexColor::rgb(238, 224, 229)),
+    ("LavenderBlush3", HexColor::rgb(205, 193, 197)),
+    ("LavenderBlush4", HexColor::rgb(139, 131, 134)),
+    ("MistyRose1", HexColor::rgb(255, 228, 225)),
+    ("MistyRose2", HexColor::rgb(238, 213, 210)),
+    ("MistyRose3", HexColor::rgb(205, 183, 181)),
+    ("MistyRose4", HexColor::rgb(139, 125, 123)),
+    ("azure1", HexColor::rgb(240, 255, 255)),
+    ("azure2", HexColor::rgb(224, 238, 238)),
+    ("azure3", HexColor::rgb(193, 205, 205)),
+    ("azure4", HexColor::rgb(131, 139, 139)),
+    ("SlateBlue1", HexColor::rgb(131, 111, 255)),
+    ("SlateBlue2", HexColor::rgb(122, 103, 238)),
+    ("SlateBlue3", HexColor::rgb(105, 89, 205)),
+    ("SlateBlue4", HexColor::rgb(71, 60, 139)),
+    ("RoyalBlue1", HexColor::rgb(72, 118, 255)),
+    ("RoyalBlue2", HexColor::rgb(67, 110, 238)),
+    ("RoyalBlue3", HexColor::rgb(58, 95, 205)),
+    ("RoyalBlue4", HexColor::rgb(39, 64, 139)),
+    ("blue1", HexColor::rgb(0, 0, 255)),
+    ("blue2", HexColor::rgb(0, 0, 238)),
+    ("blue3", HexColor::rgb(0, 0, 205)),
+    ("blue4", HexColor::rgb(0, 0, 139)),
+    ("DodgerBlue1", HexColor::rgb(30, 144, 255)),
+    ("DodgerBlue2", HexColor::rgb(28, 134, 238)),
+    ("DodgerBlue3", HexColor::rgb(24, 116, 205)),
+    ("DodgerBlue4", HexColor::rgb(16, 78, 139)),
+    ("SteelBlue1", HexColor::rgb(99, 184, 255)),
+    ("SteelBlue2", HexColor::rgb(92, 172, 238)),
+    ("SteelBlue3", HexColor::rgb(79, 148, 205)),
+    ("SteelBlue4", HexColor::rgb(54, 100, 139)),
+    ("DeepSkyBlue1", HexColor::rgb(0, 191, 255)),
+    ("DeepSkyBlue2", HexColor::rgb(0, 178, 238)),
+    ("DeepSkyBlue3", HexColor::rgb(0, 154, 205)),
+    ("DeepSkyBlue4", HexColor::rgb(0, 104, 139)),
+    ("SkyBlue1", HexColor::rgb(135, 206, 255)),
+    ("SkyBlue2", HexColor::rgb(126, 192, 238)),
+    ("SkyBlue3", HexColor::rgb(108, 166, 205)),
+    ("SkyBlue4", HexColor::rgb(74, 112, 139)),
+    ("LightSkyBlue1", HexColor::rgb(176, 226, 255)),
+    ("LightSkyBlue2", HexColor::rgb(164, 211, 238)),
+    ("LightSkyBlue3", HexColor::rgb(141, 182, 205)),
+    ("LightSkyBlue4", HexColor::rgb(96, 123, 139)),
+    ("SlateGray1", HexColor::rgb(198, 226, 255)),
+    ("SlateGray2", HexColor::rgb(185, 211, 238)),
+    ("SlateGray3", HexColor::rgb(159, 182, 205)),
+    ("SlateGray4", HexColor::rgb(108, 123, 139)),
+    ("LightSteelBlue1", HexColor::rgb(202, 225, 255)),
+    ("LightSteelBlue2", HexColor::rgb(188, 210, 238)),
+    ("LightSteelBlue3", HexColor::rgb(162, 181, 205)),
+    ("LightSteelBlue4", HexColor::rgb(110, 123, 139)),
+    ("LightBlue1", HexColor::rgb(191, 239, 255)),
+    ("LightBlue2", HexColor::rgb(178, 223, 238)),
+    ("LightBlue3", HexColor::rgb(154, 192, 205)),
+    ("LightBlue4", HexColor::rgb(104, 131, 139)),
+    ("LightCyan1", HexColor::rgb(224, 255, 255)),
+    ("LightCyan2", HexColor::rgb(209, 238, 238)),
+    ("LightCyan3", HexColor::rgb(180, 205, 205)),
+    ("LightCyan4", HexColor::rgb(122, 139, 139)),
+    ("PaleTurquoise1", HexColor::rgb(187, 255, 255)),
+    ("PaleTurquoise2", HexColor::rgb(174, 238, 238)),
+    ("PaleTurquoise3", HexColor::rgb(150, 205, 205)),
+    ("PaleTurquoise4", HexColor::rgb(102, 139, 139)),
+    ("CadetBlue1", HexColor::rgb(152, 245, 255)),
+    ("CadetBlue2", HexColor::rgb(142, 229, 238)),
+    ("CadetBlue3", HexColor::rgb(122, 197, 205)),
+    ("CadetBlue4", HexColor::rgb(83, 134, 139)),
+    ("turquoise1", HexColor::rgb(0, 245, 255)),
+    ("turquoise2", HexColor::rgb(0, 229, 238)),
+    ("turquoise3", HexColor::rgb(0, 197, 205)),
+    ("turquoise4", HexColor::rgb(0, 134, 139)),
+    ("cyan1", HexColor::rgb(0, 255, 255)),
+    ("cyan2", HexColor::rgb(0, 238, 238)),
+    ("cyan3", HexColor::rgb(0, 205, 205)),
+    ("cyan4", HexColor::rgb(0, 139, 139)),
+    ("DarkSlateGray1", HexColor::rgb(151, 255, 255)),
+    ("DarkSlateGray2", HexColor::rgb(141, 238, 238)),
+    ("DarkSlateGray3", HexColor::rgb(121, 205, 205)),
+    ("DarkSlateGray4", HexColor::rgb(82, 139, 139)),
+    ("aquamarine1", HexColor::rgb(127, 255, 212)),
+    ("aquamarine2", HexColor::rgb(118, 238, 198)),
+    ("aquamarine3", HexColor::rgb(102, 205, 170)),
+    ("aquamarine4", HexColor::rgb(69, 139, 116)),
+    ("DarkSeaGreen1", HexColor::rgb(193, 255, 193)),
+    ("DarkSeaGreen2", HexColor::rgb(180, 238, 180)),
+    ("DarkSeaGreen3", HexColor::rgb(155, 205, 155)),
+    ("DarkSeaGreen4", HexColor::rgb(105, 139, 105)),
+    ("SeaGreen1", HexColor::rgb(84, 255, 159)),
+    ("SeaGreen2", HexColor::rgb(78, 238, 148)),
+    ("SeaGreen3", HexColor::rgb(67, 205, 128)),
+    ("SeaGreen4", HexColor::rgb(46, 139, 87)),
+    ("PaleGreen1", HexColor::rgb(154, 255, 154)),
+    ("PaleGreen2", HexColor::rgb(144, 238, 144)),
+    ("PaleGreen3", HexColor::rgb(124, 205, 124)),
+    ("PaleGreen4", HexColor::rgb(84, 139, 84)),
+    ("SpringGreen1", HexColor::rgb(0, 255, 127)),
+    ("SpringGreen2", HexColor::rgb(0, 238, 118)),
+    ("SpringGreen3", HexColor::rgb(0, 205, 102)),
+    ("SpringGreen4", HexColor::rgb(0, 139, 69)),
+    ("green1", HexColor::rgb(0, 255, 0)),
+    ("green2", HexColor::rgb(0, 238, 0)),
+    ("green3", HexColor::rgb(0, 205, 0)),
+    ("green4", HexColor::rgb(0, 139, 0)),
+    ("chartreuse1", HexColor::rgb(127, 255, 0)),
+    ("chartreuse2", HexColor::rgb(118, 238, 0)),
+    ("chartreuse3", HexColor::rgb(102, 205, 0)),
+    ("chartreuse4", HexColor::rgb(69, 139, 0)),
+    ("OliveDrab1", HexColor::rgb(192, 255, 62)),
+    ("OliveDrab2", HexColor::rgb(179, 238, 58)),
+    ("OliveDrab3", HexColor::rgb(154, 205, 50)),
+    ("OliveDrab4", HexColor::rgb(105, 139, 34)),
+    ("DarkOliveGreen1", HexColor::rgb(202, 255, 112)),
+    ("DarkOliveGreen2", HexColor::rgb(188, 238, 104)),
+    ("DarkOliveGreen3", HexColor::rgb(162, 205, 90)),
+    ("DarkOliveGreen4", HexColor::rgb(110, 139, 61)),
+    ("khaki1", HexColor::rgb(255, 246, 143)),
+    ("khaki2", HexColor::rgb(238, 230, 133)),
+    ("khaki3", HexColor::rgb(205, 198, 115)),
+    ("khaki4", HexColor::rgb(139, 134, 78)),
+    ("LightGoldenrod1", HexColor::rgb(255, 236, 139)),
+    ("LightGoldenrod2", HexColor::rgb(238, 220, 130)),
+    ("LightGoldenrod3", HexColor::rgb(205, 190, 112)),
+    ("LightGoldenrod4", HexColor::rgb(139, 129, 76)),
+    ("LightYellow1", HexColor::rgb(255, 255, 224)),
+    ("LightYellow2", HexColor::rgb(238, 238, 209)),
+    ("LightYellow3", HexColor::rgb(205, 205, 180)),
+    ("LightYellow4", HexColor::rgb(139, 139, 122)),
+    ("yellow1", HexColor::rgb(255, 255, 0)),
+    ("yellow2", HexColor::rgb(238, 238, 0)),
+    ("yellow3", HexColor::rgb(205, 205, 0)),
+    ("yellow4", HexColor::rgb(139, 139, 0)),
+    ("gold1", HexColor::rgb(255, 215, 0)),
+    ("gold2", HexColor::rgb(238, 201, 0)),
+    ("gold3", HexColor::rgb(205, 173, 0)),
+    ("gold4", HexColor::rgb(139, 117, 0)),
+    ("goldenrod1", HexColor::rgb(255, 193, 37)),
+    ("goldenrod2", HexColor::rgb(238, 180, 34)),
+    ("goldenrod3", HexColor::rgb(205, 155, 29)),
+    ("goldenrod4", HexColor::rgb(139, 105, 20)),
+    ("DarkGoldenrod1", HexColor::rgb(255, 185, 15)),
+    ("DarkGoldenrod2", HexColor::rgb(238, 173, 14)),
+    ("DarkGoldenrod3", HexColor::rgb(205, 149, 12)),
+    ("DarkGoldenrod4", HexColor::rgb(139, 101, 8)),
+    ("RosyBrown1", HexColor::rgb(255, 193, 193)),
+    ("RosyBrown2", HexColor::rgb(238, 180, 180)),
+    ("RosyBrown3", HexColor::rgb(205, 155, 155)),
+    ("RosyBrown4", HexColor::rgb(139, 105, 105)),
+    ("IndianRed1", HexColor::rgb(255, 106, 106)),
+    ("IndianRed2", HexColor::rgb(238, 99, 99)),
+    ("IndianRed3", HexColor::rgb(205, 85, 85)),
+    ("IndianRed4", HexColor::rgb(139, 58, 58)),
+    ("sienna1", HexColor::rgb(255, 130, 71)),
+    ("sienna2", HexColor::rgb(238, 121, 66)),
+    ("sienna3", HexColor::rgb(205, 104, 57)),
+    ("sienna4", HexColor::rgb(139, 71, 38)),
+    ("burlywood1", HexColor::rgb(255, 211, 155)),
+    ("burlywood2", HexColor::rgb(238, 197, 145)),
+    ("burlywood3", HexColor::rgb(205, 170, 125)),
+    ("burlywood4", HexColor::rgb(139, 115, 85)),
+    ("wheat1", HexColor::rgb(255, 231, 186)),
+    ("wheat2", HexColor::rgb(238, 216, 174)),
+    ("wheat3", HexColor::rgb(205, 186, 150)),
+    ("wheat4", HexColor::rgb(139, 126, 102)),
+    ("tan1", HexColor::rgb(255, 165, 79)),
+    ("tan2", HexColor::rgb(238, 154, 73)),
+    ("tan3", HexColor::rgb(205, 133, 63)),
+    ("tan4", HexColor::rgb(139, 90, 43)),
+    ("chocolate1", HexColor::rgb(255, 127, 36)),
+    ("chocolate2", HexColor::rgb(238, 118, 33)),
+    ("chocolate3", HexColor::rgb(205, 102, 29)),
+    ("chocolate4", HexColor::rgb(139, 69, 19)),
+    ("firebrick1", HexColor::rgb(255, 48, 48)),
+    ("firebrick2", HexColor::rgb(238, 44, 44)),
+    ("firebrick3", HexColor::rgb(205, 38, 38)),
+    ("firebrick4", HexColor::rgb(139, 26, 26)),
+    ("brown1", HexColor::rgb(255, 64, 64)),
+    ("brown2", HexColor::rgb(238, 59, 59)),
+    ("brown3", HexColor::rgb(205, 51, 51)),
+    ("brown4", HexColor::rgb(139, 35, 35)),
+    ("salmon1", HexColor::rgb(255, 140, 105)),
+    ("salmon2", HexColor::rgb(238, 130, 98)),
+    ("salmon3", HexColor::rgb(205, 112, 84)),
+    ("salmon4", HexColor::rgb(139, 76, 57)),
+    ("LightSalmon1", HexColor::rgb(255, 160, 122)),
+    ("LightSalmon2", HexColor::rgb(238, 149, 114)),
+    ("LightSalmon3", HexColor::rgb(205, 129, 98)),
+    ("LightSalmon4", HexColor::rgb(139, 87, 66)),
+    ("orange1", HexColor::rgb(255, 165, 0)),
+    ("orange2", HexColor::rgb(238, 154, 0)),
+    ("orange3", HexColor::rgb(205, 133, 0)),
+    ("orange4", HexColor::rgb(139, 90, 0)),
+    ("DarkOrange1", HexColor::rgb(255, 127, 0)),
+    ("DarkOrange2", HexColor::rgb(238, 118, 0)),
+    ("DarkOrange3", HexColor::rgb(205, 102, 0)),
+    ("DarkOrange4", HexColor::rgb(139, 69, 0)),
+    ("coral1", HexColor::rgb(255, 114, 86)),
+    ("coral2", HexColor::rgb(238, 106, 80)),
+    ("coral3", HexColor::rgb(205, 91, 69)),
+    ("coral4", HexColor::rgb(139, 62, 47)),
+    ("tomato1", HexColor::rgb(255, 99, 71)),
+    ("tomato2", HexColor::rgb(238, 92, 66)),
+    ("tomato3", HexColor::rgb(205, 79, 57)),
+    ("tomato4", HexColor::rgb(139, 54, 38)),
+    ("OrangeRed1", HexColor::rgb(255, 69, 0)),
+    ("OrangeRed2", HexColor::rgb(238, 64, 0)),
+    ("OrangeRed3", HexColor::rgb(205, 55, 0)),
+    ("OrangeRed4", HexColor::rgb(139, 37, 0)),
+    ("red1", HexColor::rgb(255, 0, 0)),
+    ("red2", HexColor::rgb(238, 0, 0)),
+    ("red3", HexColor::rgb(205, 0, 0)),
+    ("red4", HexColor::rgb(139, 0, 0)),
+    ("DebianRed", HexColor::rgb(215, 7, 81)),
+    ("DeepPink1", HexColor::rgb(255, 20, 147)),
+    ("DeepPink2", HexColor::rgb(238, 18, 137)),
+    ("DeepPink3", HexColor::rgb(205, 16, 118)),
+    ("DeepPink4", HexColor::rgb(139, 10, 80)),
+    ("HotPink1", HexColor::rgb(255, 110, 180)),
+    ("HotPink2", HexColor::rgb(238, 106, 167)),
+    ("HotPink3", HexColor::rgb(205, 96, 144)),
+    ("HotPink4", HexColor::rgb(139, 58, 98)),
+    ("pink1", HexColor::rgb(255, 181, 197)),
+    ("pink2", HexColor::rgb(238, 169, 184)),
+    ("pink3", HexColor::rgb(205, 145, 158)),
+    ("pink4", HexColor::rgb(139, 99, 108)),
+    ("LightPink1", HexColor::rgb(255, 174, 185)),
+    ("LightPink2", HexColor::rgb(238, 162, 173)),
+    ("LightPink3", HexColor::rgb(205, 140, 149)),
+    ("LightPink4", HexColor::rgb(139, 95, 101)),
+    ("PaleVioletRed1", HexColor::rgb(255, 130, 171)),
+    ("PaleVioletRed2", HexColor::rgb(238, 121, 159)),
+    ("PaleVioletRed3", HexColor::rgb(205, 104, 137)),
+    ("PaleVioletRed4", HexColor::rgb(139, 71, 93)),
+    ("maroon1", HexColor::rgb(255, 52, 179)),
+    ("maroon2", HexColor::rgb(238, 48, 167)),
+    ("maroon3", HexColor::rgb(205, 41, 144)),
+    ("maroon4", HexColor::rgb(139, 28, 98)),
+    ("VioletRed1", HexColor::rgb(255, 62, 150)),
+    ("VioletRed2", HexColor::rgb(238, 58, 140)),
+    ("VioletRed3", HexColor::rgb(205, 50, 120)),
+    ("VioletRed4", HexColor::rgb(139, 34, 82)),
+    ("magenta1", HexColor::rgb(255, 0, 255)),
+    ("magenta2", HexColor::rgb(238, 0, 238)),
+    ("magenta3", HexColor::rgb(205, 0, 205)),
+    ("magenta4", HexColor::rgb(139, 0, 139)),
+    ("orchid1", HexColor::rgb(255, 131, 250)),
+    ("orchid2", HexColor::rgb(238, 122, 233)),
+    ("orchid3", HexColor::rgb(205, 105, 201)),
+    ("orchid4", HexColor::rgb(139, 71, 137)),
+    ("plum1", HexColor::rgb(255, 187, 255)),
+    ("plum2", HexColor::rgb(238, 174, 238)),
+    ("plum3", HexColor::rgb(205, 150, 205)),
+    ("plum4", HexColor::rgb(139, 102, 139)),
+    ("MediumOrchid1", HexColor::rgb(224, 102, 255)),
+    ("MediumOrchid2", HexColor::rgb(209, 95, 238)),
+    ("MediumOrchid3", HexColor::rgb(180, 82, 205)),
+    ("MediumOrchid4", HexColor::rgb(122, 55, 139)),
+    ("DarkOrchid1", HexColor::rgb(191, 62, 255)),
+    ("DarkOrchid2", HexColor::rgb(178, 58, 238)),
+    ("DarkOrchid3", HexColor::rgb(154, 50, 205)),
+    ("DarkOrchid4", HexColor::rgb(104, 34, 139)),
+    ("purple1", HexColor::rgb(155, 48, 255)),
+    ("purple2", HexColor::rgb(145, 44, 238)),
+    ("purple3", HexColor::rgb(125, 38, 205)),
+    ("purple4", HexColor::rgb(85, 26, 139)),
+    ("MediumPurple1", HexColor::rgb(171, 130, 255)),
+    ("MediumPurple2", HexColor::rgb(159, 121, 238)),
+    ("MediumPurple3", HexColor::rgb(137, 104, 205)),
+    ("MediumPurple4", HexColor::rgb(93, 71, 139)),
+    ("thistle1", HexColor::rgb(255, 225, 255)),
+    ("thistle2", HexColor::rgb(238, 210, 238)),
+    ("thistle3", HexColor::rgb(205, 181, 205)),
+    ("thistle4", HexColor::rgb(139, 123, 139)),
+    ("gray0", HexColor::rgb(0, 0, 0)),
+    ("grey0", HexColor::rgb(0, 0, 0)),
+    ("gray1", HexColor::rgb(3, 3, 3)),
+    ("grey1", HexColor::rgb(3, 3, 3)),
+    ("gray2", HexColor::rgb(5, 5, 5)),
+    ("grey2", HexColor::rgb(5, 5, 5)),
+    ("gray3", HexColor::rgb(8, 8, 8)),
+    ("grey3", HexColor::rgb(8, 8, 8)),
+    ("gray4", HexColor::rgb(10, 10, 10)),
+    ("grey4", HexColor::rgb(10, 10, 10)),
+    ("gray5", HexColor::rgb(13, 13, 13)),
+    ("grey5", HexColor::rgb(13, 13, 13)),
+    ("gray6", HexColor::rgb(15, 15, 15)),
+    ("grey6", HexColor::rgb(15, 15, 15)),
+    ("gray7", HexColor::rgb(18, 18, 18)),
+    ("grey7", HexColor::rgb(18, 18, 18)),
+    ("gray8", HexColor::rgb(20, 20, 20)),
+    ("grey8", HexColor::rgb(20, 20, 20)),
+    ("gray9", HexColor::rgb(23, 23, 23)),
+    ("grey9", HexColor::rgb(23, 23, 23)),
+    ("gray10", HexColor::rgb(26, 26, 26)),
+    ("grey10", HexColor::rgb(26, 26, 26)),
+    ("gray11", HexColor::rgb(28, 28, 28)),
+    ("grey11", HexColor::rgb(28, 28, 28)),
+    ("gray12", HexColor::rgb(31, 31, 31)),
+    ("grey12", HexColor::rgb(31, 31, 31)),
+    ("gray13", HexColor::rgb(33, 33, 33)),
+    ("grey13", HexColor::rgb(33, 33, 33)),
+    ("gray14", HexColor::rgb(36, 36, 36)),
+    ("grey14", HexColor::rgb(36, 36, 36)),
+    ("gray15", HexColor::rgb(38, 38, 38)),
+    ("grey15", HexColor::rgb(38, 38, 38)),
+    ("gray16", HexColor::rgb(41, 41, 41)),
+    ("grey16", HexColor::rgb(41, 41, 41)),
+    ("gray17", HexColor::rgb(43, 43, 43)),
+    ("grey17", HexColor::rgb(43, 43, 43)),
+    ("gray18", HexColor::rgb(46, 46, 46)),
+    ("grey18", HexColor::rgb(46, 46, 46)),
+    ("gray19", HexColor::rgb(48, 48, 48)),
+    ("grey19", HexColor::rgb(48, 48, 48)),
+    ("gray20", HexColor::rgb(51, 51, 51)),
+    ("grey20", HexColor::rgb(51, 51, 51)),
+    ("gray21", HexColor::rgb(54, 54, 54)),
+    ("grey21", HexColor::rgb(54, 54, 54)),
+    ("gray22", HexColor::rgb(56, 56, 56)),
+    ("grey22", HexColor::rgb(56, 56, 56)),
+    ("gray23", HexColor::rgb(59, 59, 59)),
+    ("grey23", HexColor::rgb(59, 59, 59)),
+    ("gray24", HexColor::rgb(61, 61, 61)),
+    ("grey24", HexColor::rgb(61, 61, 61)),
+    ("gray25", HexColor::rgb(64, 64, 64)),
+    ("grey25", HexColor::rgb(64, 64, 64)),
+    ("gray26", HexColor::rgb(66, 66, 66)),
+    ("grey26", HexColor::rgb(66, 66, 66)),
+    ("gray27", HexColor::rgb(69, 69, 69)),
+    ("grey27", HexColor::rgb(69, 69, 69)),
+    ("gray28", HexColor::rgb(71, 71, 71)),
+    ("grey28", HexColor::rgb(71, 71, 71)),
+    ("gray29", HexColor::rgb(74, 74, 74)),
+    ("grey29", HexColor::rgb(74, 74, 74)),
+    ("gray30", HexColor::rgb(77, 77, 77)),
+    ("grey30", HexColor::rgb(77, 77, 77)),
+    ("gray31", HexColor::rgb(79, 79, 79)),
+    ("grey31", HexColor::rgb(79, 79, 79)),
+    ("gray32", HexColor::rgb(82, 82, 82)),
+    ("grey32", HexColor::rgb(82, 82, 82)),
+    ("gray33", HexColor::rgb(84, 84, 84)),
+    ("grey33", HexColor::rgb(84, 84, 84)),
+    ("gray34", HexColor::rgb(87, 87, 87)),
+    ("grey34", HexColor::rgb(87, 87, 87)),
+    ("gray35", HexColor::rgb(89, 89, 89)),
+    ("grey35", HexColor::rgb(89, 89, 89)),
+    ("gray36", HexColor::rgb(92, 92, 92)),
+    ("grey36", HexColor::rgb(92, 92, 92)),
+    ("gray37", HexColor::rgb(94, 94, 94)),
+    ("grey37", HexColor::rgb(94, 94, 94)),
+    ("gray38", HexColor::rgb(97, 97, 97)),
+    ("grey38", HexColor::rgb(97, 97, 97)),
+    ("gray39", HexColor::rgb(99, 99, 99)),
+    ("grey39", HexColor::rgb(99, 99, 99)),
+    ("gray40", HexColor::rgb(102, 102, 102)),
+    ("grey40", HexColor::rgb(102, 102, 102)),
+    ("gray41", HexColor::rgb(105, 105, 105)),
+    ("grey41", HexColor::rgb(105, 105, 105)),
+    ("gray42", HexColor::rgb(107, 107, 107)),
+    ("grey42", HexColor::rgb(107, 107, 107)),
+    ("gray43", HexColor::rgb(110, 110, 110)),
+    ("grey43", HexColor::rgb(110, 110, 110)),
+    ("gray44", HexColor::rgb(112, 112, 112)),
+    ("grey44", HexColor::rgb(112, 112, 112)),
+    ("gray45", HexColor::rgb(115, 115, 115)),
+    ("grey45", HexColor::rgb(115, 115, 115)),
+    ("gray46", HexColor::rgb(117, 117, 117)),
+    ("grey46", HexColor::rgb(117, 117, 117)),
+    ("gray47", HexColor::rgb(120, 120, 120)),
+    ("grey47", HexColor::rgb(120, 120, 120)),
+    ("gray48", HexColor::rgb(122, 122, 122)),
+    ("grey48", HexColor::rgb(122, 122, 122)),
+    ("gray49", HexColor::rgb(125, 125, 125)),
+    ("grey49", HexColor::rgb(125, 125, 125)),
+    ("gray50", HexColor::rgb(127, 127, 127)),
+    ("grey50", HexColor::rgb(127, 127, 127)),
+    ("gray51", HexColor::rgb(130, 130, 130)),
+    ("grey51", HexColor::rgb(130, 130, 130)),
+    ("gray52", HexColor::rgb(133, 133, 133)),
+    ("grey52", HexColor::rgb(133, 133, 133)),
+    ("gray53", HexColor::rgb(135, 135, 135)),
+    ("grey53", HexColor::rgb(135, 135, 135)),
+    ("gray54", HexColor::rgb(138, 138, 138)),
+    ("grey54", HexColor::rgb(138, 138, 138)),
+    ("gray55", HexColor::rgb(140, 140, 140)),
+    ("grey55", HexColor::rgb(140, 140, 140)),
+    ("gray56", HexColor::rgb(143, 143, 143)),
+    ("grey56", HexColor::rgb(143, 143, 143)),
+    ("gray57", HexColor::rgb(145, 145, 145)),
+    ("grey57", HexColor::rgb(145, 145, 145)),
+    ("gray58", HexColor::rgb(148, 148, 148)),
+    ("grey58", HexColor::rgb(148, 148, 148)),
+    ("gray59", HexColor::rgb(150, 150, 150)),
+    ("grey59", HexColor::rgb(150, 150, 150)),
+    ("gray60", HexColor::rgb(153, 153, 153)),
+    ("grey60", HexColor::rgb(153, 153, 153)),
+    ("gray61", HexColor::rgb(156, 156, 156)),
+    ("grey61", HexColor::rgb(156, 156, 156)),
+    ("gray62", HexColor::rgb(158, 158, 158)),
+    ("grey62", HexColor::rgb(158, 158, 158)),
+    ("gray63", HexColor::rgb(161, 161, 161)),
+    ("grey63", HexColor::rgb(161, 161, 161)),
+    ("gray64", HexColor::rgb(163, 163, 163)),
+    ("grey64", HexColor::rgb(163, 163, 163)),
+    ("gray65", HexColor::rgb(166, 166, 166)),
+    ("grey65", HexColor::rgb(166, 166, 166)),
+    ("gray66", HexColor::rgb(168, 168, 168)),
+    ("grey66", HexColor::rgb(168, 168, 168)),
+    ("gray67", HexColor::rgb(171, 171, 171)),
+    ("grey67", HexColor::rgb(171, 171, 171)),
+    ("gray68", HexColor::rgb(173, 173, 173)),
+    ("grey68", HexColor::rgb(173, 173, 173)),
+    ("gray69", HexColor::rgb(176, 176, 176)),
+    ("grey69", HexColor::rgb(176, 176, 176)),
+    ("gray70", HexColor::rgb(179, 179, 179)),
+    ("grey70", HexColor::rgb(179, 179, 179)),
+    ("gray71", HexColor::rgb(181, 181, 181)),
+    ("grey71", HexColor::rgb(181, 181, 181)),
+    ("gray72", HexColor::rgb(184, 184, 184)),
+    ("grey72", HexColor::rgb(184, 184, 184)),
+    ("gray73", HexColor::rgb(186, 186, 186)),
+    ("grey73", HexColor::rgb(186, 186, 186)),
+    ("gray74", HexColor::rgb(189, 189, 189)),
+    ("grey74", HexColor::rgb(189, 189, 189)),
+    ("gray75", HexColor::rgb(191, 191, 191)),
+    ("grey75", HexColor::rgb(191, 191, 191)),
+    ("gray76", HexColor::rgb(194, 194, 194)),
+    ("grey76", HexColor::rgb(194, 194, 194)),
+    ("gray77", HexColor::rgb(196, 196, 196)),
+    ("grey77", HexColor::rgb(196, 196, 196)),
+    ("gray78", HexColor::rgb(199, 199, 199)),
+    ("grey78", HexColor::rgb(199, 199, 199)),
+    ("gray79", HexColor::rgb(201, 201, 201)),
+    ("grey79", HexColor::rgb(201, 201, 201)),
+    ("gray80", HexColor::rgb(204, 204, 204)),
+    ("grey80", HexColor::rgb(204, 204, 204)),
+    ("gray81", HexColor::rgb(207, 207, 207)),
+    ("grey81", HexColor::rgb(207, 207, 207)),
+    ("gray82", HexColor::rgb(209, 209, 209)),
+    ("grey82", HexColor::rgb(209, 209, 209)),
+    ("gray83", HexColor::rgb(212, 212, 212)),
+    ("grey83", HexColor::rgb(212, 212, 212)),
+    ("gray84", HexColor::rgb(214, 214, 214)),
+    ("grey84", HexColor::rgb(214, 214, 214)),
+    ("gray85", HexColor::rgb(217, 217, 217)),
+    ("grey85", HexColor::rgb(217, 217, 217)),
+    ("gray86", HexColor::rgb(219, 219, 219)),
+    ("grey86", HexColor::rgb(219, 219, 219)),
+    ("gray87", HexColor::rgb(222, 222, 222)),
+    ("grey87", HexColor::rgb(222, 222, 222)),
+    ("gray88", HexColor::rgb(224, 224, 224)),
+    ("grey88", HexColor::rgb(224, 224, 224)),
+    ("gray89", HexColor::rgb(227, 227, 227)),
+    ("grey89", HexColor::rgb(227, 227, 227)),
+    ("gray90", HexColor::rgb(229, 229, 229)),
+    ("grey90", HexColor::rgb(229, 229, 229)),
+    ("gray91", HexColor::rgb(232, 232, 232)),
+    ("grey91", HexColor::rgb(232, 232, 232)),
+    ("gray92", HexColor::rgb(235, 235, 235)),
+    ("grey92", HexColor::rgb(235, 235, 235)),
+    ("gray93", HexColor::rgb(237, 237, 237)),
+    ("grey93", HexColor::rgb(237, 237, 237)),
+    ("gray94", HexColor::rgb(240, 240, 240)),
+    ("grey94", HexColor::rgb(240, 240, 240)),
+    ("gray95", HexColor::rgb(242, 242, 242)),
+    ("grey95", HexColor::rgb(242, 242, 242)),
+    ("gray96", HexColor::rgb(245, 245, 245)),
+    ("grey96", HexColor::rgb(245, 245, 245)),
+    ("gray97", HexColor::rgb(247, 247, 247)),
+    ("grey97", HexColor::rgb(247, 247, 247)),
+    ("gray98", HexColor::rgb(250, 250, 250)),
+    ("grey98", HexColor::rgb(250, 250, 250)),
+    ("gray99", HexColor::rgb(252, 252, 252)),
+    ("grey99", HexColor::rgb(252, 252, 252)),
+    ("gray100", HexColor::rgb(255, 255, 255)),
+    ("grey100", HexColor::rgb(255, 255, 255)),
+    ("DarkGrey", HexColor::rgb(169, 169, 169)),
+    ("DarkGray", HexColor::rgb(169, 169, 169)),
+    ("DarkBlue", HexColor::rgb(0, 0, 139)),
+    ("DarkCyan", HexColor::rgb(0, 139, 139)),
+    ("DarkMagenta", HexColor::rgb(139, 0, 139)),
+    ("DarkRed", HexColor::rgb(139, 0, 0)),
+    ("LightGreen", HexColor::rgb(144, 238, 144)),
+];
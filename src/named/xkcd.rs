@@ -0,0 +1,957 @@
+//! The color names of the [xkcd color survey], as listed in its `rgb.txt`.
+//!
+//! [xkcd color survey]: https://xkcd.com/color/rgb/
+
+use crate::HexColor;
+
+pub(super) static COLORS: [(&str, HexColor); 949] = [
+    ("cloudy blue", HexColor::rgb(172, 194, 217)),
+    ("dark pastel green", HexColor::rgb(86, 174, 87)),
+    ("dust", HexColor::rgb(178, 153, 110)),
+    ("electric lime", HexColor::rgb(168, 255, 4)),
+    ("fresh green", HexColor::rgb(105, 216, 79)),
+    ("light eggplant", HexColor::rgb(137, 69, 133)),
+    ("nasty green", HexColor::rgb(112, 178, 63)),
+    ("really light blue", HexColor::rgb(212, 255, 255)),
+    ("tea", HexColor::rgb(101, 171, 124)),
+    ("warm purple", HexColor::rgb(149, 46, 143)),
+    ("yellowish tan", HexColor::rgb(252, 252, 129)),
+    ("cement", HexColor::rgb(165, 163, 145)),
+    ("dark grass green", HexColor::rgb(56, 128, 4)),
+    ("dusty teal", HexColor::rgb(76, 144, 133)),
+    ("grey teal", HexColor::rgb(94, 155, 138)),
+    ("macaroni and cheese", HexColor::rgb(239, 180, 53)),
+    ("pinkish tan", HexColor::rgb(217, 155, 130)),
+    ("spruce", HexColor::rgb(10, 95, 56)),
+    ("strong blue", HexColor::rgb(12, 6, 247)),
+    ("toxic green", HexColor::rgb(97, 222, 42)),
+    ("windows blue", HexColor::rgb(55, 120, 191)),
+    ("blue blue", HexColor::rgb(34, 66, 199)),
+    ("blue with a hint of purple", HexColor::rgb(83, 60, 198)),
+    ("booger", HexColor::rgb(155, 181, 60)),
+    ("bright sea green", HexColor::rgb(5, 255, 166)),
+    ("dark green blue", HexColor::rgb(31, 99, 87)),
+    ("deep turquoise", HexColor::rgb(1, 115, 116)),
+    ("green teal", HexColor::rgb(12, 181, 119)),
+    ("strong pink", HexColor::rgb(255, 7, 137)),
+    ("bland", HexColor::rgb(175, 168, 139)),
+    ("deep aqua", HexColor::rgb(8, 120, 127)),
+    ("lavender pink", HexColor::rgb(221, 133, 215)),
+    ("light moss green", HexColor::rgb(166, 200, 117)),
+    ("light seafoam green", HexColor::rgb(167, 255, 181)),
+    ("olive yellow", HexColor::rgb(194, 183, 9)),
+    ("pig pink", HexColor::rgb(231, 142, 165)),
+    ("deep lilac", HexColor::rgb(150, 110, 189)),
+    ("desert", HexColor::rgb(204, 173, 96)),
+    ("dusty lavender", HexColor::rgb(172, 134, 168)),
+    ("purpley grey", HexColor::rgb(148, 126, 148)),
+    ("purply", HexColor::rgb(152, 63, 178)),
+    ("candy pink", HexColor::rgb(255, 99, 233)),
+    ("light pastel green", HexColor::rgb(178, 251, 165)),
+    ("boring green", HexColor::rgb(99, 179, 101)),
+    ("kiwi green", HexColor::rgb(142, 229, 63)),
+    ("light grey green", HexColor::rgb(183, 225, 161)),
+    ("orange pink", HexColor::rgb(255, 111, 82)),
+    ("tea green", HexColor::rgb(189, 248, 163)),
+    ("very light brown", HexColor::rgb(211, 182, 131)),
+    ("egg shell", HexColor::rgb(255, 252, 196)),
+    ("eggplant purple", HexColor::rgb(67, 5, 65)),
+    ("powder pink", HexColor::rgb(255, 178, 208)),
+    ("reddish grey", HexColor::rgb(153, 117, 112)),
+    ("baby shit brown", HexColor::rgb(173, 144, 13)),
+    ("liliac", HexColor::rgb(196, 142, 253)),
+    ("stormy blue", HexColor::rgb(80, 123, 156)),
+    ("ugly brown", HexColor::rgb(125, 113, 3)),
+    ("custard", HexColor::rgb(255, 253, 120)),
+    ("darkish pink", HexColor::rgb(218, 70, 125)),
+    ("deep brown", HexColor::rgb(65, 2, 0)),
+    ("greenish beige", HexColor::rgb(201, 209, 121)),
+    ("manilla", HexColor::rgb(255, 250, 134)),
+    ("off blue", HexColor::rgb(86, 132, 174)),
+    ("battleship grey", HexColor::rgb(107, 124, 133)),
+    ("browny green", HexColor::rgb(111, 108, 10)),
+    ("bruise", HexColor::rgb(126, 64, 113)),
+    ("kelley green", HexColor::rgb(0, 147, 55)),
+    ("sickly yellow", HexColor::rgb(208, 228, 41)),
+    ("sunny yellow", HexColor::rgb(255, 249, 23)),
+    ("azul", HexColor::rgb(29, 93, 236)),
+    ("darkgreen", HexColor::rgb(5, 73, 7)),
+    ("green/yellow", HexColor::rgb(181, 206, 8)),
+    ("lichen", HexColor::rgb(143, 182, 123)),
+    ("light light green", HexColor::rgb(200, 255, 176)),
+    ("pale gold", HexColor::rgb(253, 222, 108)),
+    ("sun yellow", HexColor::rgb(255, 223, 34)),
+    ("tan green", HexColor::rgb(169, 190, 112)),
+    ("burple", HexColor::rgb(104, 50, 227)),
+    ("butterscotch", HexColor::rgb(253, 177, 71)),
+    ("toupe", HexColor::rgb(199, 172, 125)),
+    ("dark cream", HexColor::rgb(255, 243, 154)),
+    ("indian red", HexColor::rgb(133, 14, 4)),
+    ("light lavendar", HexColor::rgb(239, 192, 254)),
+    ("poison green", HexColor::rgb(64, 253, 20)),
+    ("baby puke green", HexColor::rgb(182, 196, 6)),
+    ("bright yellow green", HexColor::rgb(157, 255, 0)),
+    ("charcoal grey", HexColor::rgb(60, 65, 66)),
+    ("squash", HexColor::rgb(242, 171, 21)),
+    ("cinnamon", HexColor::rgb(172, 79, 6)),
+    ("light pea green", HexColor::rgb(196, 254, 130)),
+    ("radioactive green", HexColor::rgb(44, 250, 31)),
+    ("raw sienna", HexColor::rgb(154, 98, 0)),
+    ("baby purple", HexColor::rgb(202, 155, 247)),
+    ("cocoa", HexColor::rgb(135, 95, 66)),
+    ("light royal blue", HexColor::rgb(58, 46, 254)),
+    ("orangeish", HexColor::rgb(253, 141, 73)),
+    ("rust brown", HexColor::rgb(139, 49, 3)),
+    ("sand brown", HexColor::rgb(203, 165, 96)),
+    ("swamp", HexColor::rgb(105, 131, 57)),
+    ("tealish green", HexColor::rgb(12, 220, 115)),
+    ("burnt siena", HexColor::rgb(183, 82, 3)),
+    ("camo", HexColor::rgb(127, 143, 78)),
+    ("dusk blue", HexColor::rgb(38, 83, 141)),
+    ("fern", HexColor::rgb(99, 169, 80)),
+    ("old rose", HexColor::rgb(200, 127, 137)),
+    ("pale light green", HexColor::rgb(177, 252, 153)),
+    ("peachy pink", HexColor::rgb(255, 154, 138)),
+    ("rosy pink", HexColor::rgb(246, 104, 142)),
+    ("light bluish green", HexColor::rgb(118, 253, 168)),
+    ("light bright green", HexColor::rgb(83, 254, 92)),
+    ("light neon green", HexColor::rgb(78, 253, 84)),
+    ("light seafoam", HexColor::rgb(160, 254, 191)),
+    ("tiffany blue", HexColor::rgb(123, 242, 218)),
+    ("washed out green", HexColor::rgb(188, 245, 166)),
+    ("browny orange", HexColor::rgb(202, 107, 2)),
+    ("nice blue", HexColor::rgb(16, 122, 176)),
+    ("sapphire", HexColor::rgb(33, 56, 171)),
+    ("greyish teal", HexColor::rgb(113, 159, 145)),
+    ("orangey yellow", HexColor::rgb(253, 185, 21)),
+    ("parchment", HexColor::rgb(254, 252, 175)),
+    ("straw", HexColor::rgb(252, 246, 121)),
+    ("very dark brown", HexColor::rgb(29, 2, 0)),
+    ("terracota", HexColor::rgb(203, 104, 67)),
+    ("ugly blue", HexColor::rgb(49, 102, 138)),
+    ("clear blue", HexColor::rgb(36, 122, 253)),
+    ("creme", HexColor::rgb(255, 255, 182)),
+    ("foam green", HexColor::rgb(144, 253, 169)),
+    ("grey/green", HexColor::rgb(134, 161, 125)),
+    ("light gold", HexColor::rgb(253, 220, 92)),
+    ("seafoam blue", HexColor::rgb(120, 209, 182)),
+    ("topaz", HexColor::rgb(19, 187, 175)),
+    ("violet pink", HexColor::rgb(251, 95, 252)),
+    ("wintergreen", HexColor::rgb(32, 249, 134)),
+    ("yellow tan", HexColor::rgb(255, 227, 110)),
+    ("dark fuchsia", HexColor::rgb(157, 7, 89)),
+    ("indigo blue", HexColor::rgb(58, 24, 177)),
+    ("light yellowish green", HexColor::rgb(194, 255, 137)),
+    ("pale magenta", HexColor::rgb(215, 103, 173)),
+    ("rich purple", HexColor::rgb(114, 0, 88)),
+    ("sunflower yellow", HexColor::rgb(255, 218, 3)),
+    ("green/blue", HexColor::rgb(1, 192, 141)),
+    ("leather", HexColor::rgb(172, 116, 52)),
+    ("racing green", HexColor::rgb(1, 70, 0)),
+    ("vivid purple", HexColor::rgb(153, 0, 250)),
+    ("dark royal blue", HexColor::rgb(2, 6, 111)),
+    ("hazel", HexColor::rgb(142, 118, 24)),
+    ("muted pink", HexColor::rgb(209, 118, 143)),
+    ("booger green", HexColor::rgb(150, 180, 3)),
+    ("canary", HexColor::rgb(253, 255, 99)),
+    ("cool grey", HexColor::rgb(149, 163, 166)),
+    ("dark taupe", HexColor::rgb(127, 104, 78)),
+    ("darkish purple", HexColor::rgb(117, 25, 115)),
+    ("true green", HexColor::rgb(8, 148, 4)),
+    ("coral pink", HexColor::rgb(255, 97, 99)),
+    ("dark sage", HexColor::rgb(89, 133, 86)),
+    ("dark slate blue", HexColor::rgb(33, 71, 97)),
+    ("flat blue", HexColor::rgb(60, 115, 168)),
+    ("mushroom", HexColor::rgb(186, 158, 136)),
+    ("rich blue", HexColor::rgb(2, 27, 249)),
+    ("dirty purple", HexColor::rgb(115, 74, 101)),
+    ("greenblue", HexColor::rgb(35, 196, 139)),
+    ("icky green", HexColor::rgb(143, 174, 34)),
+    ("light khaki", HexColor::rgb(230, 242, 162)),
+    ("warm blue", HexColor::rgb(75, 87, 219)),
+    ("dark hot pink", HexColor::rgb(217, 1, 102)),
+    ("deep sea blue", HexColor::rgb(1, 84, 130)),
+    ("carmine", HexColor::rgb(157, 2, 22)),
+    ("dark yellow green", HexColor::rgb(114, 143, 2)),
+    ("pale peach", HexColor::rgb(255, 229, 173)),
+    ("plum purple", HexColor::rgb(78, 5, 80)),
+    ("golden rod", HexColor::rgb(249, 188, 8)),
+    ("neon red", HexColor::rgb(255, 7, 58)),
+    ("old pink", HexColor::rgb(199, 121, 134)),
+    ("very pale blue", HexColor::rgb(214, 255, 254)),
+    ("blood orange", HexColor::rgb(254, 75, 3)),
+    ("grapefruit", HexColor::rgb(253, 89, 86)),
+    ("sand yellow", HexColor::rgb(252, 225, 102)),
+    ("clay brown", HexColor::rgb(178, 113, 61)),
+    ("dark blue grey", HexColor::rgb(31, 59, 77)),
+    ("flat green", HexColor::rgb(105, 157, 76)),
+    ("light green blue", HexColor::rgb(86, 252, 162)),
+    ("warm pink", HexColor::rgb(251, 85, 129)),
+    ("dodger blue", HexColor::rgb(62, 130, 252)),
+    ("gross green", HexColor::rgb(160, 191, 22)),
+    ("ice", HexColor::rgb(214, 255, 250)),
+    ("metallic blue", HexColor::rgb(79, 115, 142)),
+    ("pale salmon", HexColor::rgb(255, 177, 154)),
+    ("sap green", HexColor::rgb(92, 139, 21)),
+    ("algae", HexColor::rgb(84, 172, 104)),
+    ("bluey grey", HexColor::rgb(137, 160, 176)),
+    ("greeny grey", HexColor::rgb(126, 160, 122)),
+    ("highlighter green", HexColor::rgb(27, 252, 6)),
+    ("light light blue", HexColor::rgb(202, 255, 251)),
+    ("light mint", HexColor::rgb(182, 255, 187)),
+    ("raw umber", HexColor::rgb(167, 94, 9)),
+    ("vivid blue", HexColor::rgb(21, 46, 255)),
+    ("deep lavender", HexColor::rgb(141, 94, 183)),
+    ("dull teal", HexColor::rgb(95, 158, 143)),
+    ("light greenish blue", HexColor::rgb(99, 247, 180)),
+    ("mud green", HexColor::rgb(96, 102, 2)),
+    ("pinky", HexColor::rgb(252, 134, 170)),
+    ("red wine", HexColor::rgb(140, 0, 52)),
+    ("shit green", HexColor::rgb(117, 128, 0)),
+    ("tan brown", HexColor::rgb(171, 126, 76)),
+    ("darkblue", HexColor::rgb(3, 7, 100)),
+    ("rosa", HexColor::rgb(254, 134, 164)),
+    ("lipstick", HexColor::rgb(213, 23, 78)),
+    ("pale mauve", HexColor::rgb(254, 208, 252)),
+    ("claret", HexColor::rgb(104, 0, 24)),
+    ("dandelion", HexColor::rgb(254, 223, 8)),
+    ("orangered", HexColor::rgb(254, 66, 15)),
+    ("poop green", HexColor::rgb(111, 124, 0)),
+    ("ruby", HexColor::rgb(202, 1, 71)),
+    ("dark", HexColor::rgb(27, 36, 49)),
+    ("greenish turquoise", HexColor::rgb(0, 251, 176)),
+    ("pastel red", HexColor::rgb(219, 88, 86)),
+    ("piss yellow", HexColor::rgb(221, 214, 24)),
+    ("bright cyan", HexColor::rgb(65, 253, 254)),
+    ("dark coral", HexColor::rgb(207, 82, 78)),
+    ("algae green", HexColor::rgb(33, 195, 111)),
+    ("darkish red", HexColor::rgb(169, 3, 8)),
+    ("reddy brown", HexColor::rgb(110, 16, 5)),
+    ("blush pink", HexColor::rgb(254, 130, 140)),
+    ("camouflage green", HexColor::rgb(75, 97, 19)),
+    ("lawn green", HexColor::rgb(77, 164, 9)),
+    ("putty", HexColor::rgb(190, 174, 138)),
+    ("vibrant blue", HexColor::rgb(3, 57, 248)),
+    ("dark sand", HexColor::rgb(168, 143, 89)),
+    ("purple/blue", HexColor::rgb(93, 33, 208)),
+    ("saffron", HexColor::rgb(254, 178, 9)),
+    ("twilight", HexColor::rgb(78, 81, 139)),
+    ("warm brown", HexColor::rgb(150, 78, 2)),
+    ("bluegrey", HexColor::rgb(133, 163, 178)),
+    ("bubble gum pink", HexColor::rgb(255, 105, 175)),
+    ("duck egg blue", HexColor::rgb(195, 251, 244)),
+    ("greenish cyan", HexColor::rgb(42, 254, 183)),
+    ("petrol", HexColor::rgb(0, 95, 106)),
+    ("royal", HexColor::rgb(12, 23, 147)),
+    ("butter", HexColor::rgb(255, 255, 129)),
+    ("dusty orange", HexColor::rgb(240, 131, 58)),
+    ("off yellow", HexColor::rgb(241, 243, 63)),
+    ("pale olive green", HexColor::rgb(177, 210, 123)),
+    ("orangish", HexColor::rgb(252, 130, 74)),
+    ("leaf", HexColor::rgb(113, 170, 52)),
+    ("light blue grey", HexColor::rgb(183, 201, 226)),
+    ("dried blood", HexColor::rgb(75, 1, 1)),
+    ("lightish purple", HexColor::rgb(165, 82, 230)),
+    ("rusty red", HexColor::rgb(175, 47, 13)),
+    ("lavender blue", HexColor::rgb(139, 136, 248)),
+    ("light grass green", HexColor::rgb(154, 247, 100)),
+    ("light mint green", HexColor::rgb(166, 251, 178)),
+    ("sunflower", HexColor::rgb(255, 197, 18)),
+    ("velvet", HexColor::rgb(117, 8, 81)),
+    ("brick orange", HexColor::rgb(193, 74, 9)),
+    ("lightish red", HexColor::rgb(254, 47, 74)),
+    ("pure blue", HexColor::rgb(2, 3, 226)),
+    ("twilight blue", HexColor::rgb(10, 67, 122)),
+    ("violet red", HexColor::rgb(165, 0, 85)),
+    ("yellowy brown", HexColor::rgb(174, 139, 12)),
+    ("carnation", HexColor::rgb(253, 121, 143)),
+    ("muddy yellow", HexColor::rgb(191, 172, 5)),
+    ("dark seafoam green", HexColor::rgb(62, 175, 118)),
+    ("deep rose", HexColor::rgb(199, 71, 103)),
+    ("dusty red", HexColor::rgb(185, 72, 78)),
+    ("grey/blue", HexColor::rgb(100, 125, 142)),
+    ("lemon lime", HexColor::rgb(191, 254, 40)),
+    ("purple/pink", HexColor::rgb(215, 37, 222)),
+    ("brown yellow", HexColor::rgb(178, 151, 5)),
+    ("purple brown", HexColor::rgb(103, 58, 63)),
+    ("wisteria", HexColor::rgb(168, 125, 194)),
+    ("banana yellow", HexColor::rgb(250, 254, 75)),
+    ("lipstick red", HexColor::rgb(192, 2, 47)),
+    ("water blue", HexColor::rgb(14, 135, 204)),
+    ("brown grey", HexColor::rgb(141, 132, 104)),
+    ("vibrant purple", HexColor::rgb(173, 3, 222)),
+    ("baby green", HexColor::rgb(140, 255, 158)),
+    ("barf green", HexColor::rgb(148, 172, 2)),
+    ("eggshell blue", HexColor::rgb(196, 255, 247)),
+    ("sandy yellow", HexColor::rgb(253, 238, 115)),
+    ("cool green", HexColor::rgb(51, 184, 100)),
+    ("pale", HexColor::rgb(255, 249, 208)),
+    ("blue/grey", HexColor::rgb(117, 141, 163)),
+    ("hot magenta", HexColor::rgb(245, 4, 201)),
+    ("greyblue", HexColor::rgb(119, 161, 181)),
+    ("purpley", HexColor::rgb(135, 86, 228)),
+    ("baby shit green", HexColor::rgb(136, 151, 23)),
+    ("brownish pink", HexColor::rgb(194, 126, 121)),
+    ("dark aquamarine", HexColor::rgb(1, 115, 113)),
+    ("diarrhea", HexColor::rgb(159, 131, 3)),
+    ("light mustard", HexColor::rgb(247, 213, 96)),
+    ("pale sky blue", HexColor::rgb(189, 246, 254)),
+    ("turtle green", HexColor::rgb(117, 184, 79)),
+    ("bright olive", HexColor::rgb(156, 187, 4)),
+    ("dark grey blue", HexColor::rgb(41, 70, 91)),
+    ("greeny brown", HexColor::rgb(105, 96, 6)),
+    ("lemon green", HexColor::rgb(173, 248, 2)),
+    ("light periwinkle", HexColor::rgb(193, 198, 252)),
+    ("seaweed green", HexColor::rgb(53, 173, 107)),
+    ("sunshine yellow", HexColor::rgb(255, 253, 55)),
+    ("ugly purple", HexColor::rgb(164, 66, 160)),
+    ("medium pink", HexColor::rgb(243, 97, 150)),
+    ("puke brown", HexColor::rgb(148, 119, 6)),
+    ("very light pink", HexColor::rgb(255, 244, 242)),
+    ("viridian", HexColor::rgb(30, 145, 103)),
+    ("bile", HexColor::rgb(181, 195, 6)),
+    ("faded yellow", HexColor::rgb(254, 255, 127)),
+    ("very pale green", HexColor::rgb(207, 253, 188)),
+    ("vibrant green", HexColor::rgb(10, 221, 8)),
+    ("bright lime", HexColor::rgb(135, 253, 5)),
+    ("spearmint", HexColor::rgb(30, 248, 118)),
+    ("light aquamarine", HexColor::rgb(123, 253, 199)),
+    ("light sage", HexColor::rgb(188, 236, 172)),
+    ("yellowgreen", HexColor::rgb(187, 249, 15)),
+    ("baby poo", HexColor::rgb(171, 144, 4)),
+    ("dark seafoam", HexColor::rgb(31, 181, 122)),
+    ("deep teal", HexColor::rgb(0, 85, 90)),
+    ("heather", HexColor::rgb(164, 132, 172)),
+    ("rust orange", HexColor::rgb(196, 85, 8)),
+    ("dirty blue", HexColor::rgb(63, 130, 157)),
+    ("fern green", HexColor::rgb(84, 141, 68)),
+    ("bright lilac", HexColor::rgb(201, 94, 251)),
+    ("weird green", HexColor::rgb(58, 229, 127)),
+    ("peacock blue", HexColor::rgb(1, 103, 149)),
+    ("avocado green", HexColor::rgb(135, 169, 34)),
+    ("faded orange", HexColor::rgb(240, 148, 77)),
+    ("grape purple", HexColor::rgb(93, 20, 81)),
+    ("hot green", HexColor::rgb(37, 255, 41)),
+    ("lime yellow", HexColor::rgb(208, 254, 29)),
+    ("mango", HexColor::rgb(255, 166, 43)),
+    ("shamrock", HexColor::rgb(1, 180, 76)),
+    ("bubblegum", HexColor::rgb(255, 108, 181)),
+    ("purplish brown", HexColor::rgb(107, 66, 71)),
+    ("vomit yellow", HexColor::rgb(199, 193, 12)),
+    ("pale cyan", HexColor::rgb(183, 255, 250)),
+    ("key lime", HexColor::rgb(174, 255, 110)),
+    ("tomato red", HexColor::rgb(236, 45, 1)),
+    ("lightgreen", HexColor::rgb(118, 255, 123)),
+    ("merlot", HexColor::rgb(115, 0, 57)),
+    ("night blue", HexColor::rgb(4, 3, 72)),
+    ("purpleish pink", HexColor::rgb(223, 78, 200)),
+    ("apple", HexColor::rgb(110, 203, 60)),
+    ("baby poop green", HexColor::rgb(143, 152, 5)),
+    ("green apple", HexColor::rgb(94, 220, 31)),
+    ("heliotrope", HexColor::rgb(217, 79, 245)),
+    ("yellow/green", HexColor::rgb(200, 253, 61)),
+    ("almost black", HexColor::rgb(7, 13, 13)),
+    ("cool blue", HexColor::rgb(73, 132, 184)),
+    ("leafy green", HexColor::rgb(81, 183, 59)),
+    ("mustard brown", HexColor::rgb(172, 126, 4)),
+    ("dusk", HexColor::rgb(78, 84, 129)),
+    ("dull brown", HexColor::rgb(135, 110, 75)),
+    ("frog green", HexColor::rgb(88, 188, 8)),
+    ("vivid green", HexColor::rgb(47, 239, 16)),
+    ("bright light green", HexColor::rgb(45, 254, 84)),
+    ("fluro green", HexColor::rgb(10, 255, 2)),
+    ("kiwi", HexColor::rgb(156, 239, 67)),
+    ("seaweed", HexColor::rgb(24, 209, 123)),
+    ("navy green", HexColor::rgb(53, 83, 10)),
+    ("ultramarine blue", HexColor::rgb(24, 5, 219)),
+    ("iris", HexColor::rgb(98, 88, 196)),
+    ("pastel orange", HexColor::rgb(255, 150, 79)),
+    ("yellowish orange", HexColor::rgb(255, 171, 15)),
+    ("perrywinkle", HexColor::rgb(143, 140, 231)),
+    ("tealish", HexColor::rgb(36, 188, 168)),
+    ("dark plum", HexColor::rgb(63, 1, 44)),
+    ("pear", HexColor::rgb(203, 248, 95)),
+    ("pinkish orange", HexColor::rgb(255, 114, 76)),
+    ("midnight purple", HexColor::rgb(40, 1, 55)),
+    ("light urple", HexColor::rgb(179, 111, 246)),
+    ("dark mint", HexColor::rgb(72, 192, 114)),
+    ("greenish tan", HexColor::rgb(188, 203, 122)),
+    ("light burgundy", HexColor::rgb(168, 65, 91)),
+    ("turquoise blue", HexColor::rgb(6, 177, 196)),
+    ("ugly pink", HexColor::rgb(205, 117, 132)),
+    ("sandy", HexColor::rgb(241, 218, 122)),
+    ("electric pink", HexColor::rgb(255, 4, 144)),
+    ("muted purple", HexColor::rgb(128, 91, 135)),
+    ("mid green", HexColor::rgb(80, 167, 71)),
+    ("greyish", HexColor::rgb(168, 164, 149)),
+    ("neon yellow", HexColor::rgb(207, 255, 4)),
+    ("banana", HexColor::rgb(255, 255, 126)),
+    ("carnation pink", HexColor::rgb(255, 127, 167)),
+    ("tomato", HexColor::rgb(239, 64, 38)),
+    ("sea", HexColor::rgb(60, 153, 146)),
+    ("muddy brown", HexColor::rgb(136, 104, 6)),
+    ("turquoise green", HexColor::rgb(4, 244, 137)),
+    ("buff", HexColor::rgb(254, 246, 158)),
+    ("fawn", HexColor::rgb(207, 175, 123)),
+    ("muted blue", HexColor::rgb(59, 113, 159)),
+    ("pale rose", HexColor::rgb(253, 193, 197)),
+    ("dark mint green", HexColor::rgb(32, 192, 115)),
+    ("amethyst", HexColor::rgb(155, 95, 192)),
+    ("blue/green", HexColor::rgb(15, 155, 142)),
+    ("chestnut", HexColor::rgb(116, 40, 2)),
+    ("sick green", HexColor::rgb(157, 185, 44)),
+    ("pea", HexColor::rgb(164, 191, 32)),
+    ("rusty orange", HexColor::rgb(205, 89, 9)),
+    ("stone", HexColor::rgb(173, 165, 135)),
+    ("rose red", HexColor::rgb(190, 1, 60)),
+    ("pale aqua", HexColor::rgb(184, 255, 235)),
+    ("deep orange", HexColor::rgb(220, 77, 1)),
+    ("earth", HexColor::rgb(162, 101, 62)),
+    ("mossy green", HexColor::rgb(99, 139, 39)),
+    ("grassy green", HexColor::rgb(65, 156, 3)),
+    ("pale lime green", HexColor::rgb(177, 255, 101)),
+    ("light grey blue", HexColor::rgb(157, 188, 212)),
+    ("pale grey", HexColor::rgb(253, 253, 254)),
+    ("asparagus", HexColor::rgb(119, 171, 86)),
+    ("blueberry", HexColor::rgb(70, 65, 150)),
+    ("purple red", HexColor::rgb(153, 1, 71)),
+    ("pale lime", HexColor::rgb(190, 253, 115)),
+    ("greenish teal", HexColor::rgb(50, 191, 132)),
+    ("caramel", HexColor::rgb(175, 111, 9)),
+    ("deep magenta", HexColor::rgb(160, 2, 92)),
+    ("light peach", HexColor::rgb(255, 216, 177)),
+    ("milk chocolate", HexColor::rgb(127, 78, 30)),
+    ("ocher", HexColor::rgb(191, 155, 12)),
+    ("off green", HexColor::rgb(107, 163, 83)),
+    ("purply pink", HexColor::rgb(240, 117, 230)),
+    ("lightblue", HexColor::rgb(123, 200, 246)),
+    ("dusky blue", HexColor::rgb(71, 95, 148)),
+    ("golden", HexColor::rgb(245, 191, 3)),
+    ("light beige", HexColor::rgb(255, 254, 182)),
+    ("butter yellow", HexColor::rgb(255, 253, 116)),
+    ("dusky purple", HexColor::rgb(137, 91, 123)),
+    ("french blue", HexColor::rgb(67, 107, 173)),
+    ("ugly yellow", HexColor::rgb(208, 193, 1)),
+    ("greeny yellow", HexColor::rgb(198, 248, 8)),
+    ("orangish red", HexColor::rgb(244, 54, 5)),
+    ("shamrock green", HexColor::rgb(2, 193, 77)),
+    ("orangish brown", HexColor::rgb(178, 95, 3)),
+    ("tree green", HexColor::rgb(42, 126, 25)),
+    ("deep violet", HexColor::rgb(73, 6, 72)),
+    ("gunmetal", HexColor::rgb(83, 98, 103)),
+    ("blue/purple", HexColor::rgb(90, 6, 239)),
+    ("cherry", HexColor::rgb(207, 2, 52)),
+    ("sandy brown", HexColor::rgb(196, 166, 97)),
+    ("warm grey", HexColor::rgb(151, 138, 132)),
+    ("dark indigo", HexColor::rgb(31, 9, 84)),
+    ("midnight", HexColor::rgb(3, 1, 45)),
+    ("bluey green", HexColor::rgb(43, 177, 121)),
+    ("grey pink", HexColor::rgb(195, 144, 155)),
+    ("soft purple", HexColor::rgb(166, 111, 181)),
+    ("blood", HexColor::rgb(119, 0, 1)),
+    ("brown red", HexColor::rgb(146, 43, 5)),
+    ("medium grey", HexColor::rgb(125, 127, 124)),
+    ("berry", HexColor::rgb(153, 15, 75)),
+    ("poo", HexColor::rgb(143, 115, 3)),
+    ("purpley pink", HexColor::rgb(200, 60, 185)),
+    ("light salmon", HexColor::rgb(254, 169, 147)),
+    ("snot", HexColor::rgb(172, 187, 13)),
+    ("easter purple", HexColor::rgb(192, 113, 254)),
+    ("light yellow green", HexColor::rgb(204, 253, 127)),
+    ("dark navy blue", HexColor::rgb(0, 2, 46)),
+    ("drab", HexColor::rgb(130, 131, 68)),
+    ("light rose", HexColor::rgb(255, 197, 203)),
+    ("rouge", HexColor::rgb(171, 18, 57)),
+    ("purplish red", HexColor::rgb(176, 5, 75)),
+    ("slime green", HexColor::rgb(153, 204, 4)),
+    ("baby poop", HexColor::rgb(147, 124, 0)),
+    ("irish green", HexColor::rgb(1, 149, 41)),
+    ("pink/purple", HexColor::rgb(239, 29, 231)),
+    ("dark navy", HexColor::rgb(0, 4, 53)),
+    ("greeny blue", HexColor::rgb(66, 179, 149)),
+    ("light plum", HexColor::rgb(157, 87, 131)),
+    ("pinkish grey", HexColor::rgb(200, 172, 169)),
+    ("dirty orange", HexColor::rgb(200, 118, 6)),
+    ("rust red", HexColor::rgb(170, 39, 4)),
+    ("pale lilac", HexColor::rgb(228, 203, 255)),
+    ("orangey red", HexColor::rgb(250, 66, 36)),
+    ("primary blue", HexColor::rgb(8, 4, 249)),
+    ("kermit green", HexColor::rgb(92, 178, 0)),
+    ("brownish purple", HexColor::rgb(118, 66, 78)),
+    ("murky green", HexColor::rgb(108, 122, 14)),
+    ("wheat", HexColor::rgb(251, 221, 126)),
+    ("very dark purple", HexColor::rgb(42, 1, 52)),
+    ("bottle green", HexColor::rgb(4, 74, 5)),
+    ("watermelon", HexColor::rgb(253, 70, 89)),
+    ("deep sky blue", HexColor::rgb(13, 117, 248)),
+    ("fire engine red", HexColor::rgb(254, 0, 2)),
+    ("yellow ochre", HexColor::rgb(203, 157, 6)),
+    ("pumpkin orange", HexColor::rgb(251, 125, 7)),
+    ("pale olive", HexColor::rgb(185, 204, 129)),
+    ("light lilac", HexColor::rgb(237, 200, 255)),
+    ("lightish green", HexColor::rgb(97, 225, 96)),
+    ("carolina blue", HexColor::rgb(138, 184, 254)),
+    ("mulberry", HexColor::rgb(146, 10, 78)),
+    ("shocking pink", HexColor::rgb(254, 2, 162)),
+    ("auburn", HexColor::rgb(154, 48, 1)),
+    ("bright lime green", HexColor::rgb(101, 254, 8)),
+    ("celadon", HexColor::rgb(190, 253, 183)),
+    ("pinkish brown", HexColor::rgb(177, 114, 97)),
+    ("poo brown", HexColor::rgb(136, 95, 1)),
+    ("bright sky blue", HexColor::rgb(2, 204, 254)),
+    ("celery", HexColor::rgb(193, 253, 149)),
+    ("dirt brown", HexColor::rgb(131, 101, 57)),
+    ("strawberry", HexColor::rgb(251, 41, 67)),
+    ("dark lime", HexColor::rgb(132, 183, 1)),
+    ("copper", HexColor::rgb(182, 99, 37)),
+    ("medium brown", HexColor::rgb(127, 81, 18)),
+    ("muted green", HexColor::rgb(95, 160, 82)),
+    ("robin's egg", HexColor::rgb(109, 237, 253)),
+    ("bright aqua", HexColor::rgb(11, 249, 234)),
+    ("bright lavender", HexColor::rgb(199, 96, 255)),
+    ("ivory", HexColor::rgb(255, 255, 203)),
+    ("very light purple", HexColor::rgb(246, 206, 252)),
+    ("light navy", HexColor::rgb(21, 80, 132)),
+    ("pink red", HexColor::rgb(245, 5, 79)),
+    ("olive brown", HexColor::rgb(100, 84, 3)),
+    ("poop brown", HexColor::rgb(122, 89, 1)),
+    ("mustard green", HexColor::rgb(168, 181, 4)),
+    ("ocean green", HexColor::rgb(61, 153, 115)),
+    ("very dark blue", HexColor::rgb(0, 1, 51)),
+    ("dusty green", HexColor::rgb(118, 169, 115)),
+    ("light navy blue", HexColor::rgb(46, 90, 136)),
+    ("minty green", HexColor::rgb(11, 247, 125)),
+    ("adobe", HexColor::rgb(189, 108, 72)),
+    ("barney", HexColor::rgb(172, 29, 184)),
+    ("jade green", HexColor::rgb(43, 175, 106)),
+    ("bright light blue", HexColor::rgb(38, 247, 253)),
+    ("light lime", HexColor::rgb(174, 253, 108)),
+    ("dark khaki", HexColor::rgb(155, 143, 85)),
+    ("orange yellow", HexColor::rgb(255, 173, 1)),
+    ("ocre", HexColor::rgb(198, 156, 4)),
+    ("maize", HexColor::rgb(244, 208, 84)),
+    ("faded pink", HexColor::rgb(222, 157, 172)),
+    ("british racing green", HexColor::rgb(5, 72, 13)),
+    ("sandstone", HexColor::rgb(201, 174, 116)),
+    ("mud brown", HexColor::rgb(96, 70, 15)),
+    ("light sea green", HexColor::rgb(152, 246, 176)),
+    ("robin egg blue", HexColor::rgb(138, 241, 254)),
+    ("aqua marine", HexColor::rgb(46, 232, 187)),
+    ("dark sea green", HexColor::rgb(17, 135, 93)),
+    ("soft pink", HexColor::rgb(253, 176, 192)),
+    ("orangey brown", HexColor::rgb(177, 96, 2)),
+    ("cherry red", HexColor::rgb(247, 2, 42)),
+    ("burnt yellow", HexColor::rgb(213, 171, 9)),
+    ("brownish grey", HexColor::rgb(134, 119, 95)),
+    ("camel", HexColor::rgb(198, 159, 89)),
+    ("purplish grey", HexColor::rgb(122, 104, 127)),
+    ("marine", HexColor::rgb(4, 46, 96)),
+    ("greyish pink", HexColor::rgb(200, 141, 148)),
+    ("pale turquoise", HexColor::rgb(165, 251, 213)),
+    ("pastel yellow", HexColor::rgb(255, 254, 113)),
+    ("bluey purple", HexColor::rgb(98, 65, 199)),
+    ("canary yellow", HexColor::rgb(255, 254, 64)),
+    ("faded red", HexColor::rgb(211, 73, 78)),
+    ("sepia", HexColor::rgb(152, 94, 43)),
+    ("coffee", HexColor::rgb(166, 129, 76)),
+    ("bright magenta", HexColor::rgb(255, 8, 232)),
+    ("mocha", HexColor::rgb(157, 118, 81)),
+    ("ecru", HexColor::rgb(254, 255, 202)),
+    ("purpleish", HexColor::rgb(152, 86, 141)),
+    ("cranberry", HexColor::rgb(158, 0, 58)),
+    ("darkish green", HexColor::rgb(40, 124, 55)),
+    ("brown orange", HexColor::rgb(185, 105, 2)),
+    ("dusky rose", HexColor::rgb(186, 104, 115)),
+    ("melon", HexColor::rgb(255, 120, 85)),
+    ("sickly green", HexColor::rgb(148, 178, 28)),
+    ("silver", HexColor::rgb(197, 201, 199)),
+    ("purply blue", HexColor::rgb(102, 26, 238)),
+    ("purpleish blue", HexColor::rgb(97, 64, 239)),
+    ("hospital green", HexColor::rgb(155, 229, 170)),
+    ("shit brown", HexColor::rgb(123, 88, 4)),
+    ("mid blue", HexColor::rgb(39, 106, 179)),
+    ("amber", HexColor::rgb(254, 179, 8)),
+    ("easter green", HexColor::rgb(140, 253, 126)),
+    ("soft blue", HexColor::rgb(100, 136, 234)),
+    ("cerulean blue", HexColor::rgb(5, 110, 238)),
+    ("golden brown", HexColor::rgb(178, 122, 1)),
+    ("bright turquoise", HexColor::rgb(15, 254, 249)),
+    ("red pink", HexColor::rgb(250, 42, 85)),
+    ("red purple", HexColor::rgb(130, 7, 71)),
+    ("greyish brown", HexColor::rgb(122, 106, 79)),
+    ("vermillion", HexColor::rgb(244, 50, 12)),
+    ("russet", HexColor::rgb(161, 57, 5)),
+    ("steel grey", HexColor::rgb(111, 130, 138)),
+    ("lighter purple", HexColor::rgb(165, 90, 244)),
+    ("bright violet", HexColor::rgb(173, 10, 253)),
+    ("prussian blue", HexColor::rgb(0, 69, 119)),
+    ("slate green", HexColor::rgb(101, 141, 109)),
+    ("dirty pink", HexColor::rgb(202, 123, 128)),
+    ("dark blue green", HexColor::rgb(0, 82, 73)),
+    ("pine", HexColor::rgb(43, 93, 52)),
+    ("yellowy green", HexColor::rgb(191, 241, 40)),
+    ("dark gold", HexColor::rgb(181, 148, 16)),
+    ("bluish", HexColor::rgb(41, 118, 187)),
+    ("darkish blue", HexColor::rgb(1, 65, 130)),
+    ("dull red", HexColor::rgb(187, 63, 63)),
+    ("pinky red", HexColor::rgb(252, 38, 71)),
+    ("bronze", HexColor::rgb(168, 121, 0)),
+    ("pale teal", HexColor::rgb(130, 203, 178)),
+    ("military green", HexColor::rgb(102, 124, 62)),
+    ("barbie pink", HexColor::rgb(254, 70, 165)),
+    ("bubblegum pink", HexColor::rgb(254, 131, 204)),
+    ("pea soup green", HexColor::rgb(148, 166, 23)),
+    ("dark mustard", HexColor::rgb(168, 137, 5)),
+    ("shit", HexColor::rgb(127, 95, 0)),
+    ("medium purple", HexColor::rgb(158, 67, 162)),
+    ("very dark green", HexColor::rgb(6, 46, 3)),
+    ("dirt", HexColor::rgb(138, 110, 69)),
+    ("dusky pink", HexColor::rgb(204, 122, 139)),
+    ("red violet", HexColor::rgb(158, 1, 104)),
+    ("lemon yellow", HexColor::rgb(253, 255, 56)),
+    ("pistachio", HexColor::rgb(192, 250, 139)),
+    ("dull yellow", HexColor::rgb(238, 220, 91)),
+    ("dark lime green", HexColor::rgb(126, 189, 1)),
+    ("denim blue", HexColor::rgb(59, 91, 146)),
+    ("teal blue", HexColor::rgb(1, 136, 159)),
+    ("lightish blue", HexColor::rgb(61, 122, 253)),
+    ("purpley blue", HexColor::rgb(95, 52, 231)),
+    ("light indigo", HexColor::rgb(109, 90, 207)),
+    ("swamp green", HexColor::rgb(116, 133, 0)),
+    ("brown green", HexColor::rgb(112, 108, 17)),
+    ("dark maroon", HexColor::rgb(60, 0, 8)),
+    ("hot purple", HexColor::rgb(203, 0, 245)),
+    ("dark forest green", HexColor::rgb(0, 45, 4)),
+    ("faded blue", HexColor::rgb(101, 140, 187)),
+    ("drab green", HexColor::rgb(116, 149, 81)),
+    ("light lime green", HexColor::rgb(185, 255, 102)),
+    ("snot green", HexColor::rgb(157, 193, 0)),
+    ("yellowish", HexColor::rgb(250, 238, 102)),
+    ("light blue green", HexColor::rgb(126, 251, 179)),
+    ("bordeaux", HexColor::rgb(123, 0, 44)),
+    ("light mauve", HexColor::rgb(194, 146, 161)),
+    ("ocean", HexColor::rgb(1, 123, 146)),
+    ("marigold", HexColor::rgb(252, 192, 6)),
+    ("muddy green", HexColor::rgb(101, 116, 50)),
+    ("dull orange", HexColor::rgb(216, 134, 59)),
+    ("steel", HexColor::rgb(115, 133, 149)),
+    ("electric purple", HexColor::rgb(170, 35, 255)),
+    ("fluorescent green", HexColor::rgb(8, 255, 8)),
+    ("yellowish brown", HexColor::rgb(155, 122, 1)),
+    ("blush", HexColor::rgb(242, 158, 142)),
+    ("soft green", HexColor::rgb(111, 194, 118)),
+    ("bright orange", HexColor::rgb(255, 91, 0)),
+    ("lemon", HexColor::rgb(253, 255, 82)),
+    ("purple grey", HexColor::rgb(134, 111, 133)),
+    ("acid green", HexColor::rgb(143, 254, 9)),
+    ("pale lavender", HexColor::rgb(238, 207, 254)),
+    ("violet blue", HexColor::rgb(81, 10, 201)),
+    ("light forest green", HexColor::rgb(79, 145, 83)),
+    ("burnt red", HexColor::rgb(159, 35, 5)),
+    ("khaki green", HexColor::rgb(114, 134, 57)),
+    ("cerise", HexColor::rgb(222, 12, 98)),
+    ("faded purple", HexColor::rgb(145, 110, 153)),
+    ("apricot", HexColor::rgb(255, 177, 109)),
+    ("dark olive green", HexColor::rgb(60, 77, 3)),
+    ("grey brown", HexColor::rgb(127, 112, 83)),
+    ("green grey", HexColor::rgb(119, 146, 111)),
+    ("true blue", HexColor::rgb(1, 15, 204)),
+    ("pale violet", HexColor::rgb(206, 174, 250)),
+    ("periwinkle blue", HexColor::rgb(143, 153, 251)),
+    ("light sky blue", HexColor::rgb(198, 252, 255)),
+    ("blurple", HexColor::rgb(85, 57, 204)),
+    ("green brown", HexColor::rgb(84, 78, 3)),
+    ("bluegreen", HexColor::rgb(1, 122, 121)),
+    ("bright teal", HexColor::rgb(1, 249, 198)),
+    ("brownish yellow", HexColor::rgb(201, 176, 3)),
+    ("pea soup", HexColor::rgb(146, 153, 1)),
+    ("forest", HexColor::rgb(11, 85, 9)),
+    ("barney purple", HexColor::rgb(160, 4, 152)),
+    ("ultramarine", HexColor::rgb(32, 0, 177)),
+    ("purplish", HexColor::rgb(148, 86, 140)),
+    ("puke yellow", HexColor::rgb(194, 190, 14)),
+    ("bluish grey", HexColor::rgb(116, 139, 151)),
+    ("dark periwinkle", HexColor::rgb(102, 95, 209)),
+    ("dark lilac", HexColor::rgb(156, 109, 165)),
+    ("reddish", HexColor::rgb(196, 66, 64)),
+    ("light maroon", HexColor::rgb(162, 72, 87)),
+    ("dusty purple", HexColor::rgb(130, 95, 135)),
+    ("terra cotta", HexColor::rgb(201, 100, 59)),
+    ("avocado", HexColor::rgb(144, 177, 52)),
+    ("marine blue", HexColor::rgb(1, 56, 106)),
+    ("teal green", HexColor::rgb(37, 163, 111)),
+    ("slate grey", HexColor::rgb(89, 101, 109)),
+    ("lighter green", HexColor::rgb(117, 253, 99)),
+    ("electric green", HexColor::rgb(33, 252, 13)),
+    ("dusty blue", HexColor::rgb(90, 134, 173)),
+    ("golden yellow", HexColor::rgb(254, 198, 21)),
+    ("bright yellow", HexColor::rgb(255, 253, 1)),
+    ("light lavender", HexColor::rgb(223, 197, 254)),
+    ("umber", HexColor::rgb(178, 100, 0)),
+    ("poop", HexColor::rgb(127, 94, 0)),
+    ("dark peach", HexColor::rgb(222, 126, 93)),
+    ("jungle green", HexColor::rgb(4, 130, 67)),
+    ("eggshell", HexColor::rgb(255, 255, 212)),
+    ("denim", HexColor::rgb(59, 99, 140)),
+    ("yellow brown", HexColor::rgb(183, 148, 0)),
+    ("dull purple", HexColor::rgb(132, 89, 126)),
+    ("chocolate brown", HexColor::rgb(65, 25, 0)),
+    ("wine red", HexColor::rgb(123, 3, 35)),
+    ("neon blue", HexColor::rgb(4, 217, 255)),
+    ("dirty green", HexColor::rgb(102, 126, 44)),
+    ("light tan", HexColor::rgb(251, 238, 172)),
+    ("ice blue", HexColor::rgb(215, 255, 254)),
+    ("cadet blue", HexColor::rgb(78, 116, 150)),
+    ("dark mauve", HexColor::rgb(135, 76, 98)),
+    ("very light blue", HexColor::rgb(213, 255, 255)),
+    ("grey purple", HexColor::rgb(130, 109, 140)),
+    ("pastel pink", HexColor::rgb(255, 186, 205)),
+    ("very light green", HexColor::rgb(209, 255, 189)),
+    ("dark sky blue", HexColor::rgb(68, 142, 228)),
+    ("evergreen", HexColor::rgb(5, 71, 42)),
+    ("dull pink", HexColor::rgb(213, 134, 157)),
+    ("aubergine", HexColor::rgb(61, 7, 52)),
+    ("mahogany", HexColor::rgb(74, 1, 0)),
+    ("reddish orange", HexColor::rgb(248, 72, 28)),
+    ("deep green", HexColor::rgb(2, 89, 15)),
+    ("vomit green", HexColor::rgb(137, 162, 3)),
+    ("purple pink", HexColor::rgb(224, 63, 216)),
+    ("dusty pink", HexColor::rgb(213, 138, 148)),
+    ("faded green", HexColor::rgb(123, 178, 116)),
+    ("camo green", HexColor::rgb(82, 101, 37)),
+    ("pinky purple", HexColor::rgb(201, 76, 190)),
+    ("pink purple", HexColor::rgb(219, 75, 218)),
+    ("brownish red", HexColor::rgb(158, 54, 35)),
+    ("dark rose", HexColor::rgb(181, 72, 93)),
+    ("mud", HexColor::rgb(115, 92, 18)),
+    ("brownish", HexColor::rgb(156, 109, 87)),
+    ("emerald green", HexColor::rgb(2, 143, 30)),
+    ("pale brown", HexColor::rgb(177, 145, 110)),
+    ("dull blue", HexColor::rgb(73, 117, 156)),
+    ("burnt umber", HexColor::rgb(160, 69, 14)),
+    ("medium green", HexColor::rgb(57, 173, 72)),
+    ("clay", HexColor::rgb(182, 106, 80)),
+    ("light aqua", HexColor::rgb(140, 255, 219)),
+    ("light olive green", HexColor::rgb(164, 190, 92)),
+    ("brownish orange", HexColor::rgb(203, 119, 35)),
+    ("dark aqua", HexColor::rgb(5, 105, 107)),
+    ("purplish pink", HexColor::rgb(206, 93, 174)),
+    ("dark salmon", HexColor::rgb(200, 90, 83)),
+    ("greenish grey", HexColor::rgb(150, 174, 141)),
+    ("jade", HexColor::rgb(31, 167, 116)),
+    ("ugly green", HexColor::rgb(122, 151, 3)),
+    ("dark beige", HexColor::rgb(172, 147, 98)),
+    ("emerald", HexColor::rgb(1, 160, 73)),
+    ("pale red", HexColor::rgb(217, 84, 77)),
+    ("light magenta", HexColor::rgb(250, 95, 247)),
+    ("sky", HexColor::rgb(130, 202, 252)),
+    ("light cyan", HexColor::rgb(172, 255, 252)),
+    ("yellow orange", HexColor::rgb(252, 176, 1)),
+    ("reddish purple", HexColor::rgb(145, 9, 81)),
+    ("reddish pink", HexColor::rgb(254, 44, 84)),
+    ("orchid", HexColor::rgb(200, 117, 196)),
+    ("dirty yellow", HexColor::rgb(205, 197, 10)),
+    ("orange red", HexColor::rgb(253, 65, 30)),
+    ("deep red", HexColor::rgb(154, 2, 0)),
+    ("orange brown", HexColor::rgb(190, 100, 0)),
+    ("cobalt blue", HexColor::rgb(3, 10, 167)),
+    ("neon pink", HexColor::rgb(254, 1, 154)),
+    ("rose pink", HexColor::rgb(247, 135, 154)),
+    ("greyish purple", HexColor::rgb(136, 113, 145)),
+    ("raspberry", HexColor::rgb(176, 1, 73)),
+    ("aqua green", HexColor::rgb(18, 225, 147)),
+    ("salmon pink", HexColor::rgb(254, 123, 124)),
+    ("tangerine", HexColor::rgb(255, 148, 8)),
+    ("brownish green", HexColor::rgb(106, 110, 9)),
+    ("red brown", HexColor::rgb(139, 46, 22)),
+    ("greenish brown", HexColor::rgb(105, 97, 18)),
+    ("pumpkin", HexColor::rgb(225, 119, 1)),
+    ("pine green", HexColor::rgb(10, 72, 30)),
+    ("charcoal", HexColor::rgb(52, 56, 55)),
+    ("baby pink", HexColor::rgb(255, 183, 206)),
+    ("cornflower", HexColor::rgb(106, 121, 247)),
+    ("blue violet", HexColor::rgb(93, 6, 233)),
+    ("chocolate", HexColor::rgb(61, 28, 2)),
+    ("greyish green", HexColor::rgb(130, 166, 125)),
+    ("scarlet", HexColor::rgb(190, 1, 25)),
+    ("green yellow", HexColor::rgb(201, 255, 39)),
+    ("dark olive", HexColor::rgb(55, 62, 2)),
+    ("sienna", HexColor::rgb(169, 86, 30)),
+    ("pastel purple", HexColor::rgb(202, 160, 255)),
+    ("terracotta", HexColor::rgb(202, 102, 65)),
+    ("aqua blue", HexColor::rgb(2, 216, 233)),
+    ("sage green", HexColor::rgb(136, 179, 120)),
+    ("blood red", HexColor::rgb(152, 0, 2)),
+    ("deep pink", HexColor::rgb(203, 1, 98)),
+    ("grass", HexColor::rgb(92, 172, 45)),
+    ("moss", HexColor::rgb(118, 153, 88)),
+    ("pastel blue", HexColor::rgb(162, 191, 254)),
+    ("bluish green", HexColor::rgb(16, 166, 116)),
+    ("green blue", HexColor::rgb(6, 180, 139)),
+    ("dark tan", HexColor::rgb(175, 136, 74)),
+    ("greenish blue", HexColor::rgb(11, 139, 135)),
+    ("pale orange", HexColor::rgb(255, 167, 86)),
+    ("vomit", HexColor::rgb(162, 164, 21)),
+    ("forrest green", HexColor::rgb(21, 68, 6)),
+    ("dark lavender", HexColor::rgb(133, 103, 152)),
+    ("dark violet", HexColor::rgb(52, 1, 63)),
+    ("purple blue", HexColor::rgb(99, 45, 233)),
+    ("dark cyan", HexColor::rgb(10, 136, 138)),
+    ("olive drab", HexColor::rgb(111, 118, 50)),
+    ("pinkish", HexColor::rgb(212, 106, 126)),
+    ("cobalt", HexColor::rgb(30, 72, 143)),
+    ("neon purple", HexColor::rgb(188, 19, 254)),
+    ("light turquoise", HexColor::rgb(126, 244, 204)),
+    ("apple green", HexColor::rgb(118, 205, 38)),
+    ("dull green", HexColor::rgb(116, 166, 98)),
+    ("wine", HexColor::rgb(128, 1, 63)),
+    ("powder blue", HexColor::rgb(177, 209, 252)),
+    ("off white", HexColor::rgb(255, 255, 228)),
+    ("electric blue", HexColor::rgb(6, 82, 255)),
+    ("dark turquoise", HexColor::rgb(4, 92, 90)),
+    ("blue purple", HexColor::rgb(87, 41, 206)),
+    ("azure", HexColor::rgb(6, 154, 243)),
+    ("bright red", HexColor::rgb(255, 0, 13)),
+    ("pinkish red", HexColor::rgb(241, 12, 69)),
+    ("cornflower blue", HexColor::rgb(81, 112, 215)),
+    ("light olive", HexColor::rgb(172, 191, 105)),
+    ("grape", HexColor::rgb(108, 52, 97)),
+    ("greyish blue", HexColor::rgb(94, 129, 157)),
+    ("purplish blue", HexColor::rgb(96, 30, 249)),
+    ("yellowish green", HexColor::rgb(176, 221, 22)),
+    ("greenish yellow", HexColor::rgb(205, 253, 2)),
+    ("medium blue", HexColor::rgb(44, 111, 187)),
+    ("dusty rose", HexColor::rgb(192, 115, 122)),
+    ("light violet", HexColor::rgb(214, 180, 252)),
+    ("midnight blue", HexColor::rgb(2, 0, 53)),
+    ("bluish purple", HexColor::rgb(112, 59, 231)),
+    ("red orange", HexColor::rgb(253, 60, 6)),
+    ("dark magenta", HexColor::rgb(150, 0, 86)),
+    ("greenish", HexColor::rgb(64, 163, 104)),
+    ("ocean blue", HexColor::rgb(3, 113, 156)),
+    ("coral", HexColor::rgb(252, 90, 80)),
+    ("cream", HexColor::rgb(255, 255, 194)),
+    ("reddish brown", HexColor::rgb(127, 43, 10)),
+    ("burnt sienna", HexColor::rgb(176, 78, 15)),
+    ("brick", HexColor::rgb(160, 54, 35)),
+    ("sage", HexColor::rgb(135, 174, 115)),
+    ("grey green", HexColor::rgb(120, 155, 115)),
+    ("white", HexColor::rgb(255, 255, 255)),
+    ("robin's egg blue", HexColor::rgb(152, 239, 249)),
+    ("moss green", HexColor::rgb(101, 139, 56)),
+    ("steel blue", HexColor::rgb(90, 125, 154)),
+    ("eggplant", HexColor::rgb(56, 8, 53)),
+    ("light yellow", HexColor::rgb(255, 254, 122)),
+    ("leaf green", HexColor::rgb(92, 169, 4)),
+    ("light grey", HexColor::rgb(216, 220, 214)),
+    ("puke", HexColor::rgb(165, 165, 2)),
+    ("pinkish purple", HexColor::rgb(214, 72, 215)),
+    ("sea blue", HexColor::rgb(4, 116, 149)),
+    ("pale purple", HexColor::rgb(183, 144, 212)),
+    ("slate blue", HexColor::rgb(91, 124, 153)),
+    ("blue grey", HexColor::rgb(96, 124, 142)),
+    ("hunter green", HexColor::rgb(11, 64, 8)),
+    ("fuchsia", HexColor::rgb(237, 13, 217)),
+    ("crimson", HexColor::rgb(140, 0, 15)),
+    ("pale yellow", HexColor::rgb(255, 255, 132)),
+    ("ochre", HexColor::rgb(191, 144, 5)),
+    ("mustard yellow", HexColor::rgb(210, 189, 10)),
+    ("light red", HexColor::rgb(255, 71, 76)),
+    ("cerulean", HexColor::rgb(4, 133, 209)),
+    ("pale pink", HexColor::rgb(255, 207, 220)),
+    ("deep blue", HexColor::rgb(4, 2, 115)),
+    ("rust", HexColor::rgb(168, 60, 9)),
+    ("light teal", HexColor::rgb(144, 228, 193)),
+    ("slate", HexColor::rgb(81, 101, 114)),
+    ("goldenrod", HexColor::rgb(250, 194, 5)),
+    ("dark yellow", HexColor::rgb(213, 182, 10)),
+    ("dark grey", HexColor::rgb(54, 55, 55)),
+    ("army green", HexColor::rgb(75, 93, 22)),
+    ("grey blue", HexColor::rgb(107, 139, 164)),
+    ("seafoam", HexColor::rgb(128, 249, 173)),
+    ("puce", HexColor::rgb(165, 126, 82)),
+    ("spring green", HexColor::rgb(169, 249, 113)),
+    ("dark orange", HexColor::rgb(198, 81, 2)),
+    ("sand", HexColor::rgb(226, 202, 118)),
+    ("pastel green", HexColor::rgb(176, 255, 157)),
+    ("mint", HexColor::rgb(159, 254, 176)),
+    ("light orange", HexColor::rgb(253, 170, 72)),
+    ("bright pink", HexColor::rgb(254, 1, 177)),
+    ("chartreuse", HexColor::rgb(193, 248, 10)),
+    ("deep purple", HexColor::rgb(54, 1, 63)),
+    ("dark brown", HexColor::rgb(52, 28, 2)),
+    ("taupe", HexColor::rgb(185, 162, 129)),
+    ("pea green", HexColor::rgb(142, 171, 18)),
+    ("puke green", HexColor::rgb(154, 174, 7)),
+    ("kelly green", HexColor::rgb(2, 171, 46)),
+    ("seafoam green", HexColor::rgb(122, 249, 171)),
+    ("blue green", HexColor::rgb(19, 126, 109)),
+    ("khaki", HexColor::rgb(170, 166, 98)),
+    ("burgundy", HexColor::rgb(97, 0, 35)),
+    ("dark teal", HexColor::rgb(1, 77, 78)),
+    ("brick red", HexColor::rgb(143, 20, 2)),
+    ("royal purple", HexColor::rgb(75, 0, 110)),
+    ("plum", HexColor::rgb(88, 15, 65)),
+    ("mint green", HexColor::rgb(143, 255, 159)),
+    ("gold", HexColor::rgb(219, 180, 12)),
+    ("baby blue", HexColor::rgb(162, 207, 254)),
+    ("yellow green", HexColor::rgb(192, 251, 45)),
+    ("bright purple", HexColor::rgb(190, 3, 253)),
+    ("dark red", HexColor::rgb(132, 0, 0)),
+    ("pale blue", HexColor::rgb(208, 254, 254)),
+    ("grass green", HexColor::rgb(63, 155, 11)),
+    ("navy", HexColor::rgb(1, 21, 62)),
+    ("aquamarine", HexColor::rgb(4, 216, 178)),
+    ("burnt orange", HexColor::rgb(192, 78, 1)),
+    ("neon green", HexColor::rgb(12, 255, 12)),
+    ("bright blue", HexColor::rgb(1, 101, 252)),
+    ("rose", HexColor::rgb(207, 98, 117)),
+    ("light pink", HexColor::rgb(255, 209, 223)),
+    ("mustard", HexColor::rgb(206, 179, 1)),
+    ("indigo", HexColor::rgb(56, 2, 130)),
+    ("lime", HexColor::rgb(170, 255, 50)),
+    ("sea green", HexColor::rgb(83, 252, 161)),
+    ("periwinkle", HexColor::rgb(142, 130, 254)),
+    ("dark pink", HexColor::rgb(203, 65, 107)),
+    ("olive green", HexColor::rgb(103, 122, 4)),
+    ("peach", HexColor::rgb(255, 176, 124)),
+    ("pale green", HexColor::rgb(199, 253, 181)),
+    ("light brown", HexColor::rgb(173, 129, 80)),
+    ("hot pink", HexColor::rgb(255, 2, 141)),
+    ("black", HexColor::rgb(0, 0, 0)),
+    ("lilac", HexColor::rgb(206, 162, 253)),
+    ("navy blue", HexColor::rgb(0, 17, 70)),
+    ("royal blue", HexColor::rgb(5, 4, 170)),
+    ("beige", HexColor::rgb(230, 218, 166)),
+    ("salmon", HexColor::rgb(255, 121, 108)),
+    ("olive", HexColor::rgb(110, 117, 14)),
+    ("maroon", HexColor::rgb(101, 0, 33)),
+    ("bright green", HexColor::rgb(1, 255, 7)),
+    ("dark purple", HexColor::rgb(53, 6, 62)),
+    ("mauve", HexColor::rgb(174, 113, 129)),
+    ("forest green", HexColor::rgb(6, 71, 12)),
+    ("aqua", HexColor::rgb(19, 234, 201)),
+    ("cyan", HexColor::rgb(0, 255, 255)),
+    ("tan", HexColor::rgb(209, 178, 111)),
+    ("dark blue", HexColor::rgb(0, 3, 91)),
+    ("lavender", HexColor::rgb(199, 159, 239)),
+    ("turquoise", HexColor::rgb(6, 194, 172)),
+    ("dark green", HexColor::rgb(3, 53, 0)),
+    ("violet", HexColor::rgb(154, 14, 234)),
+    ("light purple", HexColor::rgb(191, 119, 246)),
+    ("lime green", HexColor::rgb(137, 254, 5)),
+    ("grey", HexColor::rgb(146, 149, 145)),
+    ("sky blue", HexColor::rgb(117, 187, 253)),
+    ("yellow", HexColor::rgb(255, 255, 20)),
+    ("magenta", HexColor::rgb(194, 0, 120)),
+    ("light green", HexColor::rgb(150, 249, 123)),
+    ("orange", HexColor::rgb(249, 115, 6)),
+    ("teal", HexColor::rgb(2, 147, 134)),
+    ("light blue", HexColor::rgb(149, 208, 252)),
+    ("red", HexColor::rgb(229, 0, 0)),
+    ("brown", HexColor::rgb(101, 55, 0)),
+    ("pink", HexColor::rgb(255, 129, 192)),
+    ("blue", HexColor::rgb(3, 67, 223)),
+    ("green", HexColor::rgb(21, 176, 26)),
+    ("purple", HexColor::rgb(126, 30, 156)),
+];
@@ -0,0 +1,282 @@
+use crate::convert;
+use crate::math::{atan2, cos, sin, sqrt};
+use crate::{normalize_hue, HexColor, LinearRgba};
+
+/// A color in the [Oklab] color space, with an alpha component carried along
+/// unchanged from, and back to, [`HexColor`].
+///
+/// Oklab is perceptually uniform, so mixing, interpolating, and measuring
+/// distances between colors in it looks a lot more natural than doing the
+/// same in sRGB.
+///
+/// Since `a` is one of the coordinates, the alpha component is called
+/// `alpha` instead.
+///
+/// [Oklab]: https://bottosson.github.io/posts/oklab/
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Oklab};
+///
+/// // Halfway between red and blue in Oklab, rather than in sRGB:
+/// let red = Oklab::from(HexColor::RED);
+/// let blue = Oklab::from(HexColor::BLUE);
+/// let mixed = Oklab::new(
+///     (red.l + blue.l) / 2.0,
+///     (red.a + blue.a) / 2.0,
+///     (red.b + blue.b) / 2.0,
+/// );
+/// assert_eq!(HexColor::from_oklab(mixed), HexColor::rgb(140, 83, 162));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Oklab {
+    /// The perceived lightness of the color, in the range `0.0..=1.0`.
+    pub l: f32,
+    /// The position of the color between green (negative) and red
+    /// (positive), which, in practice, is in the range `-0.4..=0.4`.
+    pub a: f32,
+    /// The position of the color between blue (negative) and yellow
+    /// (positive), which, in practice, is in the range `-0.4..=0.4`.
+    pub b: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub alpha: u8,
+}
+
+impl Oklab {
+    /// Constructs a new, opaque `Oklab` color from its coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn new(l: f32, a: f32, b: f32) -> Oklab {
+        Oklab {
+            l,
+            a,
+            b,
+            alpha: 255,
+        }
+    }
+
+    /// Constructs a new `Oklab` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_alpha(self, alpha: u8) -> Oklab {
+        Oklab { alpha, ..self }
+    }
+}
+
+/// A color in the Oklch color space, the cylindrical form of [`Oklab`], with
+/// an alpha component carried along unchanged from, and back to,
+/// [`HexColor`].
+///
+/// This is the color space of CSS `oklch()`. Changing only the hue of a
+/// color, or only its lightness, works as expected in it.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, Oklch};
+///
+/// let mut oklch = Oklch::from(HexColor::rgb(255, 128, 0));
+/// oklch.h += 180.0;
+/// assert_eq!(HexColor::from_oklch(oklch), HexColor::rgb(0, 184, 255));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Oklch {
+    /// The perceived lightness of the color, in the range `0.0..=1.0`.
+    pub l: f32,
+    /// The chroma of the color, which is at least `0.0` and, in practice, at
+    /// most `0.4`.
+    pub c: f32,
+    /// The hue of the color in degrees, in the range `0.0..360.0`.
+    pub h: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub alpha: u8,
+}
+
+impl Oklch {
+    /// Constructs a new, opaque `Oklch` color from its coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn new(l: f32, c: f32, h: f32) -> Oklch {
+        Oklch {
+            l,
+            c,
+            h,
+            alpha: 255,
+        }
+    }
+
+    /// Constructs a new `Oklch` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_alpha(self, alpha: u8) -> Oklch {
+        Oklch { alpha, ..self }
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Converts the color to [`Oklab`], preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let white = HexColor::WHITE.to_oklab();
+    /// assert!((white.l - 1.0).abs() < 1e-4);
+    /// assert!(white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn to_oklab(self) -> Oklab {
+        let LinearRgba { r, g, b, .. } = self.to_linear();
+        let [l, a, b] = convert::linear_srgb_to_oklab([r, g, b]);
+        Oklab {
+            l,
+            a,
+            b,
+            alpha: self.a,
+        }
+    }
+
+    /// Converts an [`Oklab`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are mapped into it like
+    /// [`HexColor::from_oklch`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// for n in (0..=0xFF_FFFF).step_by(997) {
+    ///     let color = HexColor::from_u24(n);
+    ///     assert_eq!(HexColor::from_oklab(color.to_oklab()), color);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_oklab(oklab: Oklab) -> HexColor {
+        let rgb = convert::oklab_to_linear_srgb([oklab.l, oklab.a, oklab.b]);
+        convert::srgb_to_hex_color(convert::gamut_map_linear_srgb(rgb), oklab.alpha)
+    }
+
+    /// Converts the color to [`Oklch`], preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let oklch = HexColor::rgb(0, 0, 255).to_oklch();
+    /// assert!((oklch.l - 0.452).abs() < 1e-3);
+    /// assert!((oklch.c - 0.313).abs() < 1e-3);
+    /// assert!((oklch.h - 264.05).abs() < 1e-2);
+    /// ```
+    #[must_use]
+    pub fn to_oklch(self) -> Oklch {
+        self.to_oklab().into()
+    }
+
+    /// Converts an [`Oklch`] color to the nearest `HexColor`, preserving the
+    /// alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are mapped into it with the [CSS
+    /// gamut mapping algorithm]: the chroma is reduced, keeping the lightness
+    /// and hue, until clipping the color to the gamut makes a difference
+    /// (measured as the distance in [`Oklab`]) that's just imperceptible.
+    /// Unlike clipping on its own, this doesn't shift the hue of vivid
+    /// colors.
+    ///
+    /// [CSS gamut mapping algorithm]: https://www.w3.org/TR/css-color-4/#css-gamut-mapping
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, Oklch};
+    ///
+    /// assert_eq!(
+    ///     HexColor::from_oklch(Oklch::new(0.628, 0.2577, 29.23)),
+    ///     HexColor::RED,
+    /// );
+    ///
+    /// // Far too much chroma for sRGB:
+    /// assert_eq!(
+    ///     HexColor::from_oklch(Oklch::new(0.7, 0.4, 150.0)),
+    ///     HexColor::rgb(0, 194, 72),
+    /// );
+    ///
+    /// // Too bright for sRGB, let alone in color:
+    /// assert_eq!(
+    ///     HexColor::from_oklch(Oklch::new(1.2, 0.2, 30.0)),
+    ///     HexColor::WHITE,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_oklch(oklch: Oklch) -> HexColor {
+        HexColor::from_oklab(oklch.into())
+    }
+}
+
+impl From<HexColor> for Oklab {
+    /// Constructs a new `Oklab` from a `HexColor` via [`HexColor::to_oklab`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_oklab()
+    }
+}
+
+impl From<Oklab> for HexColor {
+    /// Constructs a new `HexColor` from an `Oklab` via
+    /// [`HexColor::from_oklab`].
+    #[inline]
+    fn from(oklab: Oklab) -> Self {
+        HexColor::from_oklab(oklab)
+    }
+}
+
+impl From<HexColor> for Oklch {
+    /// Constructs a new `Oklch` from a `HexColor` via [`HexColor::to_oklch`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.to_oklch()
+    }
+}
+
+impl From<Oklch> for HexColor {
+    /// Constructs a new `HexColor` from an `Oklch` via
+    /// [`HexColor::from_oklch`].
+    #[inline]
+    fn from(oklch: Oklch) -> Self {
+        HexColor::from_oklch(oklch)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    /// Converts an `Oklab` color to its cylindrical form, preserving the alpha
+    /// component.
+    fn from(oklab: Oklab) -> Self {
+        Oklch {
+            l: oklab.l,
+            c: sqrt(oklab.a * oklab.a + oklab.b * oklab.b),
+            h: normalize_hue(atan2(oklab.b, oklab.a).to_degrees()),
+            alpha: oklab.alpha,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    /// Converts an `Oklch` color to its rectangular form, preserving the alpha
+    /// component.
+    fn from(oklch: Oklch) -> Self {
+        let hue = oklch.h.to_radians();
+        Oklab {
+            l: oklch.l,
+            a: oklch.c * cos(hue),
+            b: oklch.c * sin(hue),
+            alpha: oklch.alpha,
+        }
+    }
+}
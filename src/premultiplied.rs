@@ -0,0 +1,200 @@
+use crate::HexColor;
+
+/// A color whose red, green, and blue components have been multiplied by its
+/// alpha component, as GPUs and most compositors expect.
+///
+/// [`HexColor`] has straight alpha, i.e., its components are independent of
+/// its alpha component. Keeping premultiplied colors in their own type means
+/// the two representations can't be mixed up. Its components can only be
+/// read, not written, so none of them is ever greater than the alpha
+/// component.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, PremultipliedHexColor};
+///
+/// let red = HexColor::rgba(255, 0, 0, 128).premultiply();
+/// assert_eq!(red.split_rgba(), (128, 0, 0, 128));
+/// assert_eq!(red.to_be_bytes(), [0x80, 0x00, 0x00, 0x80]);
+///
+/// assert_eq!(HexColor::unpremultiply(red), HexColor::rgba(255, 0, 0, 128));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PremultipliedHexColor(HexColor);
+
+impl PremultipliedHexColor {
+    /// Constructs a new premultiplied color from its components, or returns
+    /// `None` if any of `r`, `g`, or `b` is greater than `a`, which would
+    /// make it invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, PremultipliedHexColor};
+    ///
+    /// let color = PremultipliedHexColor::new(64, 32, 0, 128).unwrap();
+    /// assert_eq!(HexColor::unpremultiply(color), HexColor::rgba(128, 64, 0, 128));
+    ///
+    /// assert_eq!(PremultipliedHexColor::new(255, 0, 0, 128), None);
+    /// ```
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Option<PremultipliedHexColor> {
+        if r > a || g > a || b > a {
+            None
+        } else {
+            Some(PremultipliedHexColor(HexColor::rgba(r, g, b, a)))
+        }
+    }
+
+    /// Returns the premultiplied red component.
+    #[must_use]
+    #[inline]
+    pub const fn r(self) -> u8 {
+        self.0.r
+    }
+
+    /// Returns the premultiplied green component.
+    #[must_use]
+    #[inline]
+    pub const fn g(self) -> u8 {
+        self.0.g
+    }
+
+    /// Returns the premultiplied blue component.
+    #[must_use]
+    #[inline]
+    pub const fn b(self) -> u8 {
+        self.0.b
+    }
+
+    /// Returns the alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn a(self) -> u8 {
+        self.0.a
+    }
+
+    /// Deconstructs the color into a tuple of its components, in the order
+    /// red, green, blue, alpha.
+    #[must_use]
+    #[inline]
+    pub const fn split_rgba(self) -> (u8, u8, u8, u8) {
+        self.0.split_rgba()
+    }
+
+    /// Returns the memory representation of the color as a byte array in
+    /// big-endian byte order, i.e., in the order red, green, blue, alpha.
+    #[must_use]
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the color as a byte array in
+    /// little-endian byte order, i.e., in the order alpha, blue, green, red.
+    #[must_use]
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl HexColor {
+    /// Multiplies the red, green, and blue components of the color by its
+    /// alpha component, rounding to the nearest integer.
+    ///
+    /// Every completely transparent color becomes all zeroes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let premultiplied = HexColor::rgba(200, 100, 51, 51).premultiply();
+    /// assert_eq!(premultiplied.split_rgba(), (40, 20, 10, 51));
+    ///
+    /// assert_eq!(HexColor::WHITE.premultiply().split_rgba(), (255, 255, 255, 255));
+    /// assert_eq!(HexColor::rgba(255, 0, 0, 0).premultiply().split_rgba(), (0, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub const fn premultiply(self) -> PremultipliedHexColor {
+        // The product is at most `255 * 255`, so the quotient fits, and can
+        // never be exactly halfway between two integers, so the rounding is
+        // exact.
+        #[allow(clippy::cast_possible_truncation)]
+        const fn multiply(c: u8, a: u8) -> u8 {
+            ((c as u32 * a as u32 + 127) / 255) as u8
+        }
+
+        let a = self.a;
+        PremultipliedHexColor(HexColor::rgba(
+            multiply(self.r, a),
+            multiply(self.g, a),
+            multiply(self.b, a),
+            a,
+        ))
+    }
+
+    /// Divides the red, green, and blue components of a premultiplied color
+    /// by its alpha component, rounding to the nearest integer.
+    ///
+    /// A completely transparent color has no color to recover, so it becomes
+    /// [`HexColor::CLEAR`].
+    ///
+    /// Since premultiplying throws away precision, unpremultiplying isn't its
+    /// exact inverse for translucent colors. The other way around is,
+    /// though: premultiplying the result gives back `premultiplied`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, PremultipliedHexColor};
+    ///
+    /// let translucent = HexColor::rgba(201, 99, 50, 51);
+    /// assert_eq!(HexColor::unpremultiply(translucent.premultiply()), HexColor::rgba(200, 100, 50, 51));
+    ///
+    /// let clear = PremultipliedHexColor::new(0, 0, 0, 0).unwrap();
+    /// assert_eq!(HexColor::unpremultiply(clear), HexColor::CLEAR);
+    ///
+    /// for a in 0..=255 {
+    ///     for c in 0..=a {
+    ///         let premultiplied = PremultipliedHexColor::new(c, c, c, a).unwrap();
+    ///         assert_eq!(HexColor::unpremultiply(premultiplied).premultiply(), premultiplied);
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn unpremultiply(premultiplied: PremultipliedHexColor) -> HexColor {
+        // Since the component is at most `a`, so is the quotient.
+        #[allow(clippy::cast_possible_truncation)]
+        const fn divide(c: u8, a: u8) -> u8 {
+            let (c, a) = (c as u32, a as u32);
+            ((2 * 255 * c + a) / (2 * a)) as u8
+        }
+
+        let HexColor { r, g, b, a } = premultiplied.0;
+        if a == 0 {
+            return HexColor::CLEAR;
+        }
+        HexColor::rgba(divide(r, a), divide(g, a), divide(b, a), a)
+    }
+}
+
+impl From<HexColor> for PremultipliedHexColor {
+    /// Constructs a new `PremultipliedHexColor` from a `HexColor` via
+    /// [`HexColor::premultiply`].
+    #[inline]
+    fn from(hex_color: HexColor) -> Self {
+        hex_color.premultiply()
+    }
+}
+
+impl From<PremultipliedHexColor> for HexColor {
+    /// Constructs a new `HexColor` from a `PremultipliedHexColor` via
+    /// [`HexColor::unpremultiply`].
+    #[inline]
+    fn from(premultiplied: PremultipliedHexColor) -> Self {
+        HexColor::unpremultiply(premultiplied)
+    }
+}
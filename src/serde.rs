@@ -12,7 +12,7 @@ use arrayvec::ArrayString;
 use serde::de::{Error, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{HexColor, ParseMode};
+use crate::{HexColor, ParseMode, ParseOptions};
 
 impl HexColor {
     fn to_rgb_string(self) -> ArrayString<7> {
@@ -54,7 +54,7 @@ impl<'de> Visitor<'de> for HexColorStringVisitor {
         where
             E: Error,
     {
-        HexColor::parse_internals(s, self.mode)
+        HexColor::parse_with(s, ParseOptions::new().with_mode(self.mode))
             .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
     }
 }
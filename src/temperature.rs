@@ -0,0 +1,173 @@
+use crate::convert;
+use crate::math::sqrt;
+use crate::{HexColor, WhitePoint, Xyz};
+
+/// The lowest temperature, in kelvin, that the Planckian locus is computed
+/// for.
+const MIN_KELVIN: f32 = 1_000.0;
+/// The highest temperature, in kelvin, that the Planckian locus is computed
+/// for.
+const MAX_KELVIN: f32 = 15_000.0;
+/// How far, in CIE 1960 UCS, a color may be from the Planckian locus for its
+/// correlated color temperature to still mean anything, as per ANSI C78.377.
+const MAX_DUV: f32 = 0.05;
+
+/// Returns the CIE 1960 UCS chromaticity of a black body at `kelvin`, with the
+/// rational approximation of the Planckian locus by Krystek (1985), which is
+/// accurate to within `0.000_1` of the exact locus from 1000 K to 15000 K.
+fn planckian_uv(kelvin: f32) -> [f32; 2] {
+    let t = kelvin;
+    let t2 = t * t;
+    let u = (0.860_117_76 + 1.541_182_5e-4 * t + 1.286_412e-7 * t2)
+        / (1.0 + 8.424_202e-4 * t + 7.081_451_6e-7 * t2);
+    let v = (0.317_398_73 + 4.228_062_5e-5 * t + 4.204_817e-8 * t2)
+        / (1.0 - 2.897_418_2e-5 * t + 1.614_560_5e-7 * t2);
+    [u, v]
+}
+
+/// Returns the squared distance in CIE 1960 UCS from `[u, v]` to the
+/// Planckian locus at `mired` micro reciprocal degrees.
+fn distance_squared([u, v]: [f32; 2], mired: f32) -> f32 {
+    let [u_t, v_t] = planckian_uv(1e6 / mired);
+    let (du, dv) = (u - u_t, v - v_t);
+    du * du + dv * dv
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Constructs the opaque color of a black body, i.e., an ideal
+    /// incandescent light, at `kelvin`, as bright as possible.
+    ///
+    /// The chromaticity is computed from the Planckian locus, not from a
+    /// curve fitted to RGB values. Temperatures are clamped to the range
+    /// `1000..=15000`, and the colors of the lowest ones, which are too
+    /// saturated for sRGB, are mapped into the gamut like
+    /// [`HexColor::from_oklch`] does.
+    ///
+    /// Note that 6500 K isn't quite white: the white point of sRGB, D65, is
+    /// a daylight illuminant that lies slightly off of the Planckian locus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let candle = HexColor::from_kelvin(1_900);
+    /// assert_eq!(candle, HexColor::rgb(255, 132, 0));
+    ///
+    /// let incandescent = HexColor::from_kelvin(2_700);
+    /// assert_eq!(incandescent, HexColor::rgb(255, 173, 88));
+    ///
+    /// let daylight = HexColor::from_kelvin(6_500);
+    /// assert_eq!(daylight, HexColor::rgb(255, 248, 254));
+    ///
+    /// let blue_sky = HexColor::from_kelvin(12_000);
+    /// assert_eq!(blue_sky, HexColor::rgb(192, 209, 255));
+    /// ```
+    #[must_use]
+    pub fn from_kelvin(kelvin: u32) -> HexColor {
+        // Temperatures large enough to be rounded are clamped anyway.
+        #[allow(clippy::cast_precision_loss)]
+        let [u, v] = planckian_uv((kelvin as f32).clamp(MIN_KELVIN, MAX_KELVIN));
+        let xyz = [3.0 * u / (2.0 * v), 1.0, (4.0 - u - 10.0 * v) / (2.0 * v)];
+
+        let rgb = convert::multiply(&convert::XYZ_D65_TO_LINEAR_SRGB, xyz);
+        let brightest = rgb[0].max(rgb[1]).max(rgb[2]);
+        let rgb = rgb.map(|c| c / brightest);
+        convert::srgb_to_hex_color(convert::gamut_map_linear_srgb(rgb), u8::MAX)
+    }
+
+    /// Estimates the correlated color temperature (CCT) of the color in
+    /// kelvin, i.e., the temperature of the black body whose color is
+    /// closest to it in CIE 1960 UCS, ignoring the alpha component.
+    ///
+    /// Returns `None` if the color is black, if it's further than `0.05` from
+    /// the Planckian locus, where a color temperature stops being meaningful,
+    /// or if the temperature isn't in the range `1000.0..=15000.0`. See
+    /// [`HexColor::cct_duv`] for the distance itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let cct = HexColor::from_kelvin(2_700).cct().unwrap();
+    /// assert!((cct - 2_700.0).abs() < 10.0);
+    ///
+    /// assert_eq!(HexColor::GREEN.cct(), None);
+    /// assert_eq!(HexColor::BLACK.cct(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cct(self) -> Option<f32> {
+        self.cct_duv().map(|(cct, _)| cct)
+    }
+
+    /// Estimates the correlated color temperature (CCT) of the color in
+    /// kelvin, like [`HexColor::cct`] does, along with its distance from the
+    /// Planckian locus in CIE 1960 UCS, known as `Duv`.
+    ///
+    /// `Duv` is positive for colors above the locus, which look greenish, and
+    /// negative for colors below it, which look pinkish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::HexColor;
+    ///
+    /// let (cct, duv) = HexColor::WHITE.cct_duv().unwrap();
+    /// assert!((cct - 6_504.0).abs() < 5.0);
+    /// assert!((duv - 0.003_2).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    pub fn cct_duv(self) -> Option<(f32, f32)> {
+        const MIN_MIRED: f32 = 1e6 / MAX_KELVIN;
+        const MAX_MIRED: f32 = 1e6 / MIN_KELVIN;
+        const STEP: f32 = 10.0;
+
+        let Xyz { x, y, z, .. } = self.to_xyz(WhitePoint::D65);
+        let denominator = x + 15.0 * y + 3.0 * z;
+        if denominator <= 0.0 {
+            return None;
+        }
+        let uv = [4.0 * x / denominator, 6.0 * y / denominator];
+
+        // The temperature is searched for in mireds, in which the locus is
+        // spaced a lot more evenly than in kelvin: a coarse scan brackets the
+        // closest point, and a golden-section search then narrows it down.
+        let mut closest = MIN_MIRED;
+        let mut mired = MIN_MIRED;
+        while mired <= MAX_MIRED {
+            if distance_squared(uv, mired) < distance_squared(uv, closest) {
+                closest = mired;
+            }
+            mired += STEP;
+        }
+
+        let ratio = (sqrt(5.0) - 1.0) / 2.0;
+        let mut low = (closest - STEP).max(MIN_MIRED);
+        let mut high = (closest + STEP).min(MAX_MIRED);
+        while high - low > 1e-3 {
+            let a = high - ratio * (high - low);
+            let b = low + ratio * (high - low);
+            if distance_squared(uv, a) < distance_squared(uv, b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+        // If the search never left the edge of the range, the closest point
+        // is outside of it.
+        if low <= MIN_MIRED || high >= MAX_MIRED {
+            return None;
+        }
+        let mired = f32::midpoint(low, high);
+
+        let duv = sqrt(distance_squared(uv, mired));
+        if duv > MAX_DUV {
+            return None;
+        }
+        let [_, v_t] = planckian_uv(1e6 / mired);
+        Some((1e6 / mired, duv.copysign(uv[1] - v_t)))
+    }
+}
@@ -0,0 +1,195 @@
+use crate::convert::{self, Matrix};
+use crate::{HexColor, LinearRgba};
+
+/// The reference white of a CIE XYZ color, or of a color space derived from
+/// it, such as [`Lab`](crate::Lab).
+///
+/// Converting between white points uses the Bradford chromatic adaptation
+/// transform.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub enum WhitePoint {
+    /// CIE standard illuminant D50, i.e., horizon light at about 5000 K. This
+    /// is the white point of print, ICC profiles, and CSS `lab()` and `lch()`.
+    D50,
+    /// CIE standard illuminant D65, i.e., noon daylight at about 6500 K. This
+    /// is the white point of sRGB, and so of [`HexColor`].
+    #[default]
+    D65,
+}
+
+impl WhitePoint {
+    /// Returns the CIE XYZ coordinates of the white point itself, normalized
+    /// so that `y` is `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// let d65 = WhitePoint::D65.xyz();
+    /// assert_eq!(d65.y, 1.0);
+    ///
+    /// let white = HexColor::WHITE.to_xyz(WhitePoint::D65);
+    /// assert!((white.x - d65.x).abs() < 1e-6);
+    /// assert!((white.z - d65.z).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub const fn xyz(self) -> Xyz {
+        let [x, y, z] = match self {
+            WhitePoint::D50 => [0.964_295_7, 1.0, 0.825_104_6],
+            WhitePoint::D65 => [0.950_455_9, 1.0, 1.089_057_8],
+        };
+        Xyz::new(x, y, z)
+    }
+}
+
+/// A color in the CIE 1931 XYZ color space, with an alpha component carried
+/// along unchanged from, and back to, [`HexColor`].
+///
+/// The coordinates are relative to a [`WhitePoint`], which isn't stored in
+/// the color, and are scaled so that the white point has a `y` of `1.0`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, WhitePoint};
+///
+/// let orange = HexColor::rgb(255, 128, 0);
+///
+/// let xyz = orange.to_xyz(WhitePoint::D65);
+/// assert!((xyz.y - 0.3670).abs() < 1e-4);
+///
+/// assert_eq!(HexColor::from_xyz(xyz, WhitePoint::D65), orange);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub struct Xyz {
+    /// The `X` coordinate of the color.
+    pub x: f32,
+    /// The `Y` coordinate of the color, i.e., its luminance.
+    pub y: f32,
+    /// The `Z` coordinate of the color.
+    pub z: f32,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl Xyz {
+    /// Constructs a new, opaque `Xyz` color from its coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32) -> Xyz {
+        Xyz { x, y, z, a: 255 }
+    }
+
+    /// Constructs a new `Xyz` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> Xyz {
+        Xyz { a, ..self }
+    }
+
+    /// Adapts the color from being relative to the white point `from` to
+    /// being relative to `to`, with the Bradford chromatic adaptation
+    /// transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::WhitePoint;
+    ///
+    /// let d50 = WhitePoint::D65.xyz().adapt(WhitePoint::D65, WhitePoint::D50);
+    /// assert!((d50.x - WhitePoint::D50.xyz().x).abs() < 1e-6);
+    /// assert!((d50.z - WhitePoint::D50.xyz().z).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> Xyz {
+        let matrix = match (from, to) {
+            (WhitePoint::D50, WhitePoint::D65) => &D50_TO_D65,
+            (WhitePoint::D65, WhitePoint::D50) => &D65_TO_D50,
+            _ => return self,
+        };
+        Xyz::from_array(convert::multiply(matrix, self.to_array()), self.a)
+    }
+
+    pub(crate) const fn from_array([x, y, z]: [f32; 3], a: u8) -> Xyz {
+        Xyz { x, y, z, a }
+    }
+
+    pub(crate) const fn to_array(self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl HexColor {
+    /// Converts the color to CIE XYZ relative to `white`, preserving the
+    /// alpha component.
+    ///
+    /// sRGB is defined relative to D65, so [`WhitePoint::D50`] adapts the
+    /// color with the Bradford transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// let black = HexColor::BLACK.to_xyz(WhitePoint::D50);
+    /// assert_eq!((black.x, black.y, black.z), (0.0, 0.0, 0.0));
+    ///
+    /// let white = HexColor::WHITE.to_xyz(WhitePoint::D50);
+    /// assert!((white.y - 1.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn to_xyz(self, white: WhitePoint) -> Xyz {
+        let LinearRgba { r, g, b, .. } = self.to_linear();
+        let xyz = convert::multiply(&LINEAR_SRGB_TO_XYZ_D65, [r, g, b]);
+        Xyz::from_array(xyz, self.a).adapt(WhitePoint::D65, white)
+    }
+
+    /// Converts a CIE XYZ color relative to `white` to the nearest
+    /// `HexColor`, preserving the alpha component.
+    ///
+    /// Colors outside of the sRGB gamut are clipped, i.e., each channel is
+    /// clamped to its range independently, which can shift the hue of very
+    /// saturated colors. [`HexColor::from_oklch`] maps colors into the gamut
+    /// more gracefully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, WhitePoint};
+    ///
+    /// let white = WhitePoint::D50.xyz();
+    /// assert_eq!(HexColor::from_xyz(white, WhitePoint::D50), HexColor::WHITE);
+    ///
+    /// let green = HexColor::rgb(0, 128, 0).to_xyz(WhitePoint::D65);
+    /// assert_eq!(HexColor::from_xyz(green, WhitePoint::D65), HexColor::rgb(0, 128, 0));
+    /// ```
+    #[must_use]
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> HexColor {
+        let xyz = xyz.adapt(white, WhitePoint::D65);
+        let rgb = convert::multiply(&convert::XYZ_D65_TO_LINEAR_SRGB, xyz.to_array());
+        let rgb = rgb.map(|c| convert::linear_to_srgb(c.clamp(0.0, 1.0)));
+        convert::srgb_to_hex_color(rgb, xyz.a)
+    }
+}
+
+const LINEAR_SRGB_TO_XYZ_D65: Matrix = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_65, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+const D65_TO_D50: Matrix = [
+    [1.047_929_8, 0.022_946_87, -0.050_192_267],
+    [0.029_627_81, 0.990_434_4, -0.017_073_799],
+    [-0.009_243_04, 0.015_055_192, 0.751_874_27],
+];
+
+const D50_TO_D65: Matrix = [
+    [0.955_473_4, -0.023_098_456, 0.063_259_244],
+    [-0.028_369_71, 1.009_995_3, 0.021_041_442],
+    [0.012_314_015, -0.020_507_649, 1.330_365_9],
+];
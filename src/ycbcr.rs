@@ -0,0 +1,278 @@
+use crate::{round_to_u8, HexColor};
+
+/// A color in the 8-bit Y′CbCr model that digital video uses, with an alpha
+/// component carried along unchanged from, and back to, [`HexColor`].
+///
+/// Y′CbCr splits a gamma-encoded color into its luma, `y`, and two
+/// color-difference components, `cb` and `cr`, which are centered on `128`.
+/// How exactly depends on the [`YCbCrMatrix`] of the video standard and on the
+/// [`YCbCrRange`] of the codes.
+///
+/// # Examples
+///
+/// ```
+/// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange, YCbCr};
+///
+/// let white = HexColor::WHITE.to_ycbcr(YCbCrMatrix::Bt709, YCbCrRange::Limited);
+/// assert_eq!(white, YCbCr::new(235, 128, 128));
+///
+/// let orange = HexColor::rgb(255, 128, 0);
+/// let ycbcr = orange.to_ycbcr(YCbCrMatrix::Bt709, YCbCrRange::Full);
+/// assert_eq!(ycbcr, YCbCr::new(146, 49, 197));
+/// assert_eq!(HexColor::from_ycbcr(ycbcr, YCbCrMatrix::Bt709, YCbCrRange::Full), orange);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct YCbCr {
+    /// The luma component of the color.
+    pub y: u8,
+    /// The blue-difference component of the color.
+    pub cb: u8,
+    /// The red-difference component of the color.
+    pub cr: u8,
+    /// The alpha component of the color (`0` is transparent, `255` is opaque).
+    pub a: u8,
+}
+
+impl YCbCr {
+    /// Constructs a new, opaque `YCbCr` color from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(y: u8, cb: u8, cr: u8) -> YCbCr {
+        YCbCr { y, cb, cr, a: 255 }
+    }
+
+    /// Constructs a new `YCbCr` color with the given alpha component.
+    #[must_use]
+    #[inline]
+    pub const fn with_a(self, a: u8) -> YCbCr {
+        YCbCr { a, ..self }
+    }
+}
+
+/// The luma coefficients of a video standard, which determine how much red,
+/// green, and blue contribute to the luma of a [`YCbCr`] color.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum YCbCrMatrix {
+    /// ITU-R BT.601, used by standard-definition video and JPEG.
+    Bt601,
+    /// ITU-R BT.709, used by high-definition video.
+    #[default]
+    Bt709,
+    /// ITU-R BT.2020 (non-constant luminance), used by ultra-high-definition
+    /// video.
+    Bt2020,
+}
+
+/// The range of codes a [`YCbCr`] color uses.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum YCbCrRange {
+    /// Every code is used: luma goes from `0` to `255`, and so do the
+    /// color-difference components. This is what JPEG and most computer
+    /// graphics use.
+    Full,
+    /// Luma goes from `16` to `235`, and the color-difference components go
+    /// from `16` to `240`, leaving headroom and footroom. This is what
+    /// broadcast and most other video uses, and is also known as "TV" or
+    /// "studio" range.
+    #[default]
+    Limited,
+}
+
+/// Everything that's needed to convert between `HexColor` and `YCbCr`,
+/// computed once from a [`YCbCrMatrix`] and a [`YCbCrRange`].
+#[derive(Copy, Clone)]
+struct Coefficients {
+    kr: f32,
+    kg: f32,
+    kb: f32,
+    y_offset: f32,
+    y_scale: f32,
+    c_scale: f32,
+}
+
+impl Coefficients {
+    fn new(matrix: YCbCrMatrix, range: YCbCrRange) -> Coefficients {
+        let (kr, kb) = match matrix {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+            YCbCrMatrix::Bt2020 => (0.2627, 0.0593),
+        };
+        let (y_offset, y_scale, c_scale) = match range {
+            YCbCrRange::Full => (0.0, 255.0, 255.0),
+            YCbCrRange::Limited => (16.0, 219.0, 224.0),
+        };
+        Coefficients {
+            kr,
+            kg: 1.0 - kr - kb,
+            kb,
+            y_offset,
+            y_scale,
+            c_scale,
+        }
+    }
+
+    fn encode(self, color: HexColor) -> YCbCr {
+        let [r, g, b] = [color.r, color.g, color.b].map(|c| f32::from(c) / 255.0);
+        let y = self.kr * r + self.kg * g + self.kb * b;
+        let pb = (b - y) / (2.0 * (1.0 - self.kb));
+        let pr = (r - y) / (2.0 * (1.0 - self.kr));
+
+        YCbCr {
+            y: round_to_u8(self.y_offset + self.y_scale * y),
+            cb: round_to_u8(128.0 + self.c_scale * pb),
+            cr: round_to_u8(128.0 + self.c_scale * pr),
+            a: color.a,
+        }
+    }
+
+    fn decode(self, ycbcr: YCbCr) -> HexColor {
+        let y = (f32::from(ycbcr.y) - self.y_offset) / self.y_scale;
+        let pb = (f32::from(ycbcr.cb) - 128.0) / self.c_scale;
+        let pr = (f32::from(ycbcr.cr) - 128.0) / self.c_scale;
+
+        let r = y + 2.0 * (1.0 - self.kr) * pr;
+        let b = y + 2.0 * (1.0 - self.kb) * pb;
+        let g = (y - self.kr * r - self.kb * b) / self.kg;
+
+        let [r, g, b] = [r, g, b].map(|c| round_to_u8(c * 255.0));
+        HexColor::rgba(r, g, b, ycbcr.a)
+    }
+}
+
+impl HexColor {
+    /// Converts the color to [`YCbCr`] with the luma coefficients of `matrix`
+    /// and the codes of `range`, preserving the alpha component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange, YCbCr};
+    ///
+    /// let black = HexColor::BLACK.to_ycbcr(YCbCrMatrix::Bt601, YCbCrRange::Limited);
+    /// assert_eq!(black, YCbCr::new(16, 128, 128));
+    ///
+    /// let green = HexColor::GREEN;
+    /// assert_eq!(green.to_ycbcr(YCbCrMatrix::Bt601, YCbCrRange::Full), YCbCr::new(150, 44, 21));
+    /// assert_eq!(green.to_ycbcr(YCbCrMatrix::Bt709, YCbCrRange::Full), YCbCr::new(182, 30, 12));
+    /// assert_eq!(green.to_ycbcr(YCbCrMatrix::Bt2020, YCbCrRange::Full), YCbCr::new(173, 36, 11));
+    /// ```
+    #[must_use]
+    pub fn to_ycbcr(self, matrix: YCbCrMatrix, range: YCbCrRange) -> YCbCr {
+        Coefficients::new(matrix, range).encode(self)
+    }
+
+    /// Converts a [`YCbCr`] color with the luma coefficients of `matrix` and
+    /// the codes of `range` to the nearest `HexColor`, preserving the alpha
+    /// component.
+    ///
+    /// Codes outside of the nominal range, e.g., a luma of `240` in
+    /// [`YCbCrRange::Limited`], and combinations that don't correspond to an RGB
+    /// color are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange, YCbCr};
+    ///
+    /// let white = YCbCr::new(235, 128, 128);
+    /// assert_eq!(HexColor::from_ycbcr(white, YCbCrMatrix::Bt709, YCbCrRange::Limited), HexColor::WHITE);
+    ///
+    /// let super_white = YCbCr::new(250, 128, 128);
+    /// assert_eq!(HexColor::from_ycbcr(super_white, YCbCrMatrix::Bt709, YCbCrRange::Limited), HexColor::WHITE);
+    /// ```
+    ///
+    /// Since there are fewer `YCbCr` colors than RGB ones, converting to
+    /// [`YCbCr`] and back isn't lossless: each component can be off by one in
+    /// [`YCbCrRange::Full`], and by two in [`YCbCrRange::Limited`].
+    ///
+    /// ```
+    /// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange};
+    ///
+    /// let teal = HexColor::rgb(0, 128, 128);
+    /// let ycbcr = teal.to_ycbcr(YCbCrMatrix::Bt709, YCbCrRange::Full);
+    /// assert_eq!(HexColor::from_ycbcr(ycbcr, YCbCrMatrix::Bt709, YCbCrRange::Full), HexColor::rgb(0, 128, 129));
+    /// ```
+    #[must_use]
+    pub fn from_ycbcr(ycbcr: YCbCr, matrix: YCbCrMatrix, range: YCbCrRange) -> HexColor {
+        Coefficients::new(matrix, range).decode(ycbcr)
+    }
+
+    /// Converts every color of `colors` to [`YCbCr`] like
+    /// [`HexColor::to_ycbcr`], writing the results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` and `out` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange, YCbCr};
+    ///
+    /// let palette = [HexColor::BLACK, HexColor::GRAY, HexColor::WHITE];
+    /// let mut ycbcr = [YCbCr::default(); 3];
+    /// HexColor::to_ycbcr_slice(&palette, &mut ycbcr, YCbCrMatrix::Bt709, YCbCrRange::Limited);
+    ///
+    /// assert_eq!(
+    ///     ycbcr,
+    ///     [
+    ///         YCbCr::new(16, 128, 128),
+    ///         YCbCr::new(126, 128, 128),
+    ///         YCbCr::new(235, 128, 128),
+    ///     ],
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn to_ycbcr_slice(
+        colors: &[HexColor],
+        out: &mut [YCbCr],
+        matrix: YCbCrMatrix,
+        range: YCbCrRange,
+    ) {
+        assert_eq!(
+            colors.len(),
+            out.len(),
+            "source and destination slices have different lengths",
+        );
+        let coefficients = Coefficients::new(matrix, range);
+        for (color, ycbcr) in colors.iter().zip(out) {
+            *ycbcr = coefficients.encode(*color);
+        }
+    }
+
+    /// Converts every color of `ycbcr` to the nearest `HexColor` like
+    /// [`HexColor::from_ycbcr`], writing the results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ycbcr` and `out` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_color::{HexColor, YCbCrMatrix, YCbCrRange, YCbCr};
+    ///
+    /// let ycbcr = [YCbCr::new(16, 128, 128), YCbCr::new(235, 128, 128)];
+    /// let mut palette = [HexColor::default(); 2];
+    /// HexColor::from_ycbcr_slice(&ycbcr, &mut palette, YCbCrMatrix::Bt709, YCbCrRange::Limited);
+    ///
+    /// assert_eq!(palette, [HexColor::BLACK, HexColor::WHITE]);
+    /// ```
+    #[track_caller]
+    pub fn from_ycbcr_slice(
+        ycbcr: &[YCbCr],
+        out: &mut [HexColor],
+        matrix: YCbCrMatrix,
+        range: YCbCrRange,
+    ) {
+        assert_eq!(
+            ycbcr.len(),
+            out.len(),
+            "source and destination slices have different lengths",
+        );
+        let coefficients = Coefficients::new(matrix, range);
+        for (ycbcr, color) in ycbcr.iter().zip(out) {
+            *color = coefficients.decode(*ycbcr);
+        }
+    }
+}